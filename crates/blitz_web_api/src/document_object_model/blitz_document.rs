@@ -47,3 +47,24 @@ impl BlitzDocument {
         })
     }
 }
+
+#[cfg(test)]
+impl BlitzDocument {
+    /// Installs an empty document with an `<html>` root element, like the one `leptos_blitz`
+    /// mounts its views into. The document is thread-local, so every test gets its own.
+    pub(crate) fn set_test_document() {
+        use super::document::qual_name;
+        use blitz_dom::{ElementNodeData, NodeData};
+        use blitz_traits::{ColorScheme, Viewport};
+
+        let mut doc = Document::new(Viewport::new(0, 0, 1.0, ColorScheme::Light));
+        let html = doc.create_node(NodeData::Element(ElementNodeData::new(
+            qual_name("html", None),
+            Vec::new(),
+        )));
+        let root = doc.root_node().id;
+        doc.get_node_mut(html).unwrap().parent = Some(root);
+        doc.get_node_mut(root).unwrap().children.push(html);
+        Self::set_document(doc);
+    }
+}
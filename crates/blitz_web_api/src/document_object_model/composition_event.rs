@@ -0,0 +1,88 @@
+use super::event::{Event, EventInit};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct CompositionEventInit {
+    pub event: EventInit,
+    pub data: String,
+    pub locale: String,
+    /// The cursor position within `data` as a byte range, as reported by the input method.
+    pub cursor_range: Option<(usize, usize)>,
+}
+
+impl CompositionEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompositionEvent(Event);
+
+impl CompositionEvent {
+    #[doc = "The `new CompositionEvent(..)` constructor, creating a new instance of `CompositionEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CompositionEvent/CompositionEvent)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_event_init_dict(type_, &CompositionEventInit::new())
+    }
+
+    #[doc = "The `new CompositionEvent(..)` constructor, creating a new instance of `CompositionEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CompositionEvent/CompositionEvent)"]
+    pub fn new_with_event_init_dict(type_: &str, event_init_dict: &CompositionEventInit) -> Self {
+        Self(Event::new_with_details(
+            type_,
+            &event_init_dict.event,
+            vec![Rc::new(event_init_dict.clone())],
+        ))
+    }
+
+    fn init(&self) -> Option<&CompositionEventInit> {
+        self.0.detail()
+    }
+
+    #[doc = "Getter for the `data` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CompositionEvent/data)"]
+    pub fn data(&self) -> Option<String> {
+        self.init().map(|init| init.data.clone())
+    }
+
+    #[doc = "Getter for the `locale` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CompositionEvent/locale)"]
+    pub fn locale(&self) -> String {
+        self.init()
+            .map(|init| init.locale.clone())
+            .unwrap_or_default()
+    }
+
+    /// The cursor position within the preedit [`data`](Self::data) as a byte range, if the
+    /// input method reported one.
+    ///
+    /// This has no web equivalent; browsers render the preedit text themselves.
+    pub fn cursor_range(&self) -> Option<(usize, usize)> {
+        self.init().and_then(|init| init.cursor_range)
+    }
+}
+
+impl Deref for CompositionEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for CompositionEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CompositionEvent> for Event {
+    fn from(value: CompositionEvent) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,201 @@
+use super::{blitz_document::BlitzDocument, element::Element};
+use blitz_dom::{NodeData, RestyleHint};
+use style::{
+    context::QuirksMode,
+    properties::{
+        parse_one_declaration_into, Importance, PropertyDeclarationBlock, PropertyId,
+        SourcePropertyDeclaration,
+    },
+    servo_arc::Arc as ServoArc,
+    stylesheets::{CssRuleType, Origin, UrlExtraData},
+};
+use style_traits::ParsingMode;
+use url::Url;
+
+/// The inline style of an element, edited one declaration at a time.
+///
+/// Declarations are set on and removed from the element's parsed declaration block, and only the
+/// element itself is restyled. Unlike in browsers, the `style` attribute isn't rewritten to match,
+/// so it keeps the value it was last set to.
+#[derive(Debug, Clone)]
+pub struct CssStyleDeclaration(Element);
+
+impl CssStyleDeclaration {
+    pub(super) fn new(element: Element) -> Self {
+        Self(element)
+    }
+
+    #[doc = "The `getPropertyValue()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/getPropertyValue)"]
+    pub fn get_property_value(&self, property: &str) -> String {
+        let mut value = String::new();
+        let Ok(id) = PropertyId::parse_enabled_for_all_content(property) else {
+            return value;
+        };
+
+        let doc = BlitzDocument::document();
+        let style_attribute = doc
+            .get_node(self.0.node_id())
+            .and_then(|node| node.element_data())
+            .and_then(|element| element.style_attribute.as_ref());
+        if let Some(style_attribute) = style_attribute {
+            let guard = doc.guard().read();
+            let _ = style_attribute
+                .read_with(&guard)
+                .property_value_to_css(&id, &mut value);
+        }
+        value
+    }
+
+    #[doc = "The `setProperty()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/setProperty)"]
+    pub fn set_property(&self, property: &str, value: &str) {
+        // as in browsers, setting an empty value removes the declaration
+        if value.is_empty() {
+            self.remove_property(property);
+            return;
+        }
+        let Ok(id) = PropertyId::parse_enabled_for_all_content(property) else {
+            return;
+        };
+
+        let mut declarations = SourcePropertyDeclaration::default();
+        let url_data = UrlExtraData::from(
+            "data:text/css;charset=utf-8;base64,"
+                .parse::<Url>()
+                .unwrap(),
+        );
+        let parsed = parse_one_declaration_into(
+            &mut declarations,
+            id,
+            value,
+            Origin::Author,
+            &url_data,
+            None,
+            ParsingMode::DEFAULT,
+            QuirksMode::NoQuirks,
+            CssRuleType::Style,
+        );
+        // an invalid value leaves the current declaration in place
+        if parsed.is_err() {
+            return;
+        }
+
+        self.update(|block| block.extend(declarations.drain(), Importance::Normal));
+    }
+
+    #[doc = "The `removeProperty()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/removeProperty)"]
+    pub fn remove_property(&self, property: &str) -> String {
+        let Ok(id) = PropertyId::parse_enabled_for_all_content(property) else {
+            return String::new();
+        };
+
+        let value = self.get_property_value(property);
+        self.update(|block| match block.first_declaration_to_remove(&id) {
+            Some(first_declaration) => {
+                block.remove_property(&id, first_declaration);
+                true
+            }
+            None => false,
+        });
+        value
+    }
+
+    /// Edits the element's parsed declaration block in place, creating it if the element has no
+    /// inline style yet. When `update` reports a change, the element is marked for restyling.
+    fn update(&self, update: impl FnOnce(&mut PropertyDeclarationBlock) -> bool) {
+        let node_id = self.0.node_id();
+        let guard = BlitzDocument::document().guard();
+
+        let doc = BlitzDocument::document_mut();
+        let node = &mut doc.nodes[node_id];
+        let NodeData::Element(ref mut element) = node.raw_dom_data else {
+            return;
+        };
+
+        let style_attribute = element
+            .style_attribute
+            .get_or_insert_with(|| ServoArc::new(guard.wrap(PropertyDeclarationBlock::new())));
+        if !update(style_attribute.write_with(&mut guard.write())) {
+            return;
+        }
+
+        // the declarations only apply to this element, so its descendants only need to inherit
+        // the new values
+        let stylo_element_data = &mut *node.stylo_element_data.borrow_mut();
+        if let Some(data) = stylo_element_data {
+            data.hint |= RestyleHint::RESTYLE_SELF;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dom::{window, BlitzDocument, Element};
+
+    fn element(style: Option<&str>) -> Element {
+        BlitzDocument::set_test_document();
+        let el = window().document().create_element_ns(None, "div");
+        if let Some(style) = style {
+            el.set_attribute("style", style);
+        }
+        el
+    }
+
+    #[test]
+    fn set_property_edits_the_declaration_block_in_place() {
+        let el = element(Some("color: red"));
+        let style = el.style();
+        assert_eq!(style.get_property_value("color"), "red");
+
+        style.set_property("margin-top", "4px");
+        style.set_property("color", "blue");
+        assert_eq!(style.get_property_value("margin-top"), "4px");
+        assert_eq!(style.get_property_value("color"), "blue");
+
+        // the attribute isn't rebuilt from the block
+        assert_eq!(el.get_attribute("style").as_deref(), Some("color: red"));
+    }
+
+    #[test]
+    fn set_property_creates_the_declaration_block() {
+        let el = element(None);
+        let style = el.style();
+        style.set_property("color", "red");
+        style.set_property("padding", "1px");
+        assert_eq!(style.get_property_value("color"), "red");
+        assert_eq!(style.get_property_value("padding"), "1px");
+        assert_eq!(el.get_attribute("style"), None);
+    }
+
+    #[test]
+    fn invalid_properties_and_values_are_ignored() {
+        let style = element(Some("color: red")).style();
+        style.set_property("color", "red; margin: 0");
+        style.set_property("not a property", "0");
+        assert_eq!(style.get_property_value("color"), "red");
+        assert_eq!(style.get_property_value("margin"), "");
+    }
+
+    #[test]
+    fn remove_property_keeps_the_other_declarations() {
+        let el = element(Some("color: red; margin: 0"));
+        let style = el.style();
+        assert_eq!(style.remove_property("color"), "red");
+        assert_eq!(style.get_property_value("color"), "");
+        assert_eq!(style.get_property_value("margin"), "0");
+        assert_eq!(style.remove_property("color"), "");
+
+        // as in browsers, an empty value removes the declaration
+        style.set_property("margin", "");
+        assert_eq!(style.get_property_value("margin"), "");
+        assert_eq!(
+            el.get_attribute("style").as_deref(),
+            Some("color: red; margin: 0")
+        );
+    }
+}
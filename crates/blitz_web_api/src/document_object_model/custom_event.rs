@@ -0,0 +1,101 @@
+use super::event::{Event, EventInit};
+use std::{any::Any, fmt::Debug, marker::PhantomData, ops::Deref, rc::Rc};
+
+/// The init dictionary of a [`CustomEvent`], whose `detail` is a Rust value of type `T`.
+#[derive(Debug, Clone)]
+pub struct CustomEventInit<T = ()> {
+    pub event: EventInit,
+    pub detail: Option<T>,
+}
+
+impl<T> Default for CustomEventInit<T> {
+    fn default() -> Self {
+        Self {
+            event: EventInit::default(),
+            detail: None,
+        }
+    }
+}
+
+impl<T> CustomEventInit<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[doc = "Change the `detail` field of this object."]
+    pub fn set_detail(&mut self, val: T) {
+        self.detail = Some(val);
+    }
+}
+
+/// An event carrying application data of type `T` in its `detail`.
+///
+/// Listeners receive the event as a `CustomEvent` of the type they were registered with, so
+/// `detail()` is `None` if the event was created with a detail of another type.
+pub struct CustomEvent<T = ()>(Event, PhantomData<fn() -> T>);
+
+impl<T: Any + Clone> CustomEvent<T> {
+    #[doc = "The `new CustomEvent(..)` constructor, creating a new instance of `CustomEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/CustomEvent)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_event_init_dict(type_, &CustomEventInit::new())
+    }
+
+    #[doc = "The `new CustomEvent(..)` constructor, creating a new instance of `CustomEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/CustomEvent)"]
+    pub fn new_with_event_init_dict(type_: &str, event_init_dict: &CustomEventInit<T>) -> Self {
+        Self(
+            Event::new_with_details(
+                type_,
+                &event_init_dict.event,
+                vec![Rc::new(event_init_dict.clone())],
+            ),
+            PhantomData,
+        )
+    }
+}
+
+impl<T: Any> CustomEvent<T> {
+    #[doc = "Getter for the `detail` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/detail)"]
+    pub fn detail(&self) -> Option<&T> {
+        self.0
+            .detail::<CustomEventInit<T>>()
+            .and_then(|init| init.detail.as_ref())
+    }
+}
+
+impl<T> Clone for CustomEvent<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T> Debug for CustomEvent<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CustomEvent").field(&self.0).finish()
+    }
+}
+
+impl<T> Deref for CustomEvent<T> {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<Event> for CustomEvent<T> {
+    fn from(value: Event) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<T> From<CustomEvent<T>> for Event {
+    fn from(value: CustomEvent<T>) -> Self {
+        value.0
+    }
+}
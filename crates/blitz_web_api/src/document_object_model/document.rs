@@ -2,6 +2,7 @@ use super::{
     blitz_document::BlitzDocument,
     comment::Comment,
     element::Element,
+    event_target::{EventTarget, EventTargetId},
     node::{Node, NodeId},
     text::Text,
 };
@@ -19,6 +20,19 @@ pub(super) fn qual_name(local_name: &str, namespace: Option<&str>) -> QualName {
 pub struct Document(Node);
 
 impl Document {
+    #[doc = "Getter for the `activeElement` field of this object."]
+    #[doc = ""]
+    #[doc = "The focused element, or the root element if nothing is focused."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Document/activeElement)"]
+    pub fn active_element(&self) -> Option<Element> {
+        let doc = BlitzDocument::document();
+        let node_id = doc
+            .get_focussed_node_id()
+            .unwrap_or_else(|| doc.root_element().id);
+        Some(Element::from(node_id))
+    }
+
     #[doc = "The `createComment()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Document/createComment)"]
@@ -45,6 +59,12 @@ impl Document {
     }
 }
 
+impl EventTarget for Document {
+    fn event_target_id(&self) -> EventTargetId {
+        self.0.event_target_id()
+    }
+}
+
 impl From<NodeId> for Document {
     fn from(value: NodeId) -> Self {
         Self(Node::from(value))
@@ -0,0 +1,226 @@
+use super::{blitz_document::BlitzDocument, document::qual_name, element::Element};
+use blitz_dom::{local_name, node::Attribute, NodeData, RestyleHint};
+
+/// The `class` attribute of an element, viewed as a set of whitespace-separated tokens.
+///
+/// As in browsers, duplicate tokens are dropped whenever the list is changed. Where browsers throw
+/// for a token that is empty or contains whitespace, the methods here leave the list unchanged.
+#[derive(Debug, Clone)]
+pub struct DomTokenList(Element);
+
+impl DomTokenList {
+    pub(super) fn new(element: Element) -> Self {
+        Self(element)
+    }
+
+    #[doc = "Getter for the `value` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/value)"]
+    pub fn value(&self) -> String {
+        self.0.get_attribute("class").unwrap_or_default()
+    }
+
+    #[doc = "The `contains()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/contains)"]
+    pub fn contains(&self, token: &str) -> bool {
+        self.value().split_ascii_whitespace().any(|t| t == token)
+    }
+
+    #[doc = "The `add()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/add)"]
+    pub fn add(&self, token: &str) {
+        self.update(token, |tokens| {
+            if !tokens.iter().any(|t| t == token) {
+                tokens.push(token.to_owned());
+            }
+        });
+    }
+
+    #[doc = "The `remove()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/remove)"]
+    pub fn remove(&self, token: &str) {
+        self.update(token, |tokens| tokens.retain(|t| t != token));
+    }
+
+    #[doc = "The `toggle()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/toggle)"]
+    pub fn toggle(&self, token: &str) -> bool {
+        let force = !self.contains(token);
+        self.toggle_with_force(token, force)
+    }
+
+    #[doc = "The `toggle()` method, adding the token when `force` is true and removing it otherwise."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/toggle)"]
+    pub fn toggle_with_force(&self, token: &str, force: bool) -> bool {
+        if force {
+            self.add(token);
+        } else {
+            self.remove(token);
+        }
+        self.contains(token)
+    }
+
+    /// Edits the token list for `token`, then rewrites the `class` attribute if its value changed
+    /// and marks only this element for restyling. Descendants matched through the changed class
+    /// are picked up by stylo's invalidation, using the snapshot taken before the edit.
+    fn update(&self, token: &str, update: impl FnOnce(&mut Vec<String>)) {
+        if !is_valid_token(token) {
+            return;
+        }
+        let node_id = self.0.node_id();
+        let current = self.value();
+        let mut tokens = parse_tokens(&current);
+        update(&mut tokens);
+        let value = tokens.join(" ");
+        if value == current {
+            return;
+        }
+
+        let doc = BlitzDocument::document_mut();
+        doc.snapshot_node(node_id);
+        let node = &mut doc.nodes[node_id];
+
+        let stylo_element_data = &mut *node.stylo_element_data.borrow_mut();
+        if let Some(data) = stylo_element_data {
+            data.hint |= RestyleHint::RESTYLE_SELF;
+        }
+
+        if let NodeData::Element(ref mut element) = node.raw_dom_data {
+            let existing_attr = element
+                .attrs
+                .iter_mut()
+                .find(|attr| attr.name.local == local_name!("class"));
+
+            if let Some(existing_attr) = existing_attr {
+                existing_attr.value = value;
+            } else {
+                element.attrs.push(Attribute {
+                    name: qual_name("class", None),
+                    value,
+                });
+            }
+        }
+    }
+}
+
+/// Splits a `class` value into its tokens, keeping only the first of any duplicates.
+fn parse_tokens(value: &str) -> Vec<String> {
+    let mut tokens = Vec::<String>::new();
+    for token in value.split_ascii_whitespace() {
+        if !tokens.iter().any(|t| t == token) {
+            tokens.push(token.to_owned());
+        }
+    }
+    tokens
+}
+
+fn is_valid_token(token: &str) -> bool {
+    !token.is_empty() && !token.contains(|c: char| c.is_ascii_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::window;
+
+    fn class_list(class: Option<&str>) -> (Element, DomTokenList) {
+        BlitzDocument::set_test_document();
+        let el = window().document().create_element_ns(None, "div");
+        if let Some(class) = class {
+            el.set_attribute("class", class);
+        }
+        (el.clone(), el.class_list())
+    }
+
+    #[test]
+    fn add_and_remove_update_the_class_attribute() {
+        let (el, list) = class_list(None);
+        list.add("a");
+        list.add("b");
+        list.add("a");
+        assert_eq!(el.get_attribute("class").as_deref(), Some("a b"));
+
+        list.remove("a");
+        list.remove("c");
+        assert_eq!(el.get_attribute("class").as_deref(), Some("b"));
+        list.remove("b");
+        assert_eq!(el.get_attribute("class").as_deref(), Some(""));
+
+        // removing from an element without a class doesn't add the attribute
+        let (el, list) = class_list(None);
+        list.remove("a");
+        assert_eq!(el.get_attribute("class"), None);
+    }
+
+    #[test]
+    fn contains_sees_the_class_attribute() {
+        let (el, list) = class_list(Some("  a\tb\n"));
+        assert!(list.contains("a"));
+        assert!(list.contains("b"));
+        assert!(!list.contains("a b"));
+        assert!(!list.contains(""));
+
+        el.set_attribute("class", "c");
+        assert!(!list.contains("a"));
+        assert!(list.contains("c"));
+    }
+
+    #[test]
+    fn edits_normalize_whitespace_and_duplicates() {
+        type Edit = fn(&DomTokenList);
+        #[rustfmt::skip]
+        let cases: [(&str, Edit, &str); 5] = [
+            ("  a \t b  a ", |list| list.add("c"), "a b c"),
+            ("a b a", |list| list.add("a"), "a b"),
+            ("a b a", |list| list.remove("a"), "b"),
+            ("a\nb", |list| list.remove("c"), "a b"),
+            ("a a", |list| { list.toggle("b"); }, "a b"),
+        ];
+
+        for (class, edit, expected) in cases {
+            let (el, list) = class_list(Some(class));
+            edit(&list);
+            assert_eq!(
+                el.get_attribute("class").as_deref(),
+                Some(expected),
+                "{class:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_tokens_leave_the_list_unchanged() {
+        let (el, list) = class_list(Some("a  b"));
+        list.add("");
+        list.add("c d");
+        list.remove("a b");
+        assert!(!list.toggle("e\tf"));
+        assert_eq!(el.get_attribute("class").as_deref(), Some("a  b"));
+    }
+
+    #[test]
+    fn toggle_flips_the_token() {
+        let (el, list) = class_list(Some("a"));
+        assert!(list.toggle("b"));
+        assert_eq!(el.get_attribute("class").as_deref(), Some("a b"));
+        assert!(!list.toggle("a"));
+        assert_eq!(el.get_attribute("class").as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn toggle_with_force_only_adds_or_only_removes() {
+        let (el, list) = class_list(Some("a"));
+        assert!(list.toggle_with_force("a", true));
+        assert!(list.toggle_with_force("b", true));
+        assert_eq!(el.get_attribute("class").as_deref(), Some("a b"));
+
+        assert!(!list.toggle_with_force("a", false));
+        assert!(!list.toggle_with_force("c", false));
+        assert_eq!(el.get_attribute("class").as_deref(), Some("b"));
+    }
+}
@@ -1,12 +1,16 @@
 use super::{
     blitz_document::BlitzDocument,
+    css_style_declaration::CssStyleDeclaration,
     document::qual_name,
+    dom_token_list::DomTokenList,
+    event_target::{EventTarget, EventTargetId},
+    focus_event::dispatch_focus_change,
     node::{Node, NodeId},
     DomError,
 };
 use blitz_dom::{
     local_name, namespace_url,
-    node::{Attribute, NodeSpecificData},
+    node::{Attribute, DisplayOuter, NodeSpecificData},
     ns, ElementNodeData, NodeData, QualName, RestyleHint,
 };
 use std::ops::Deref;
@@ -51,25 +55,17 @@ impl Element {
                     existing_attr.value.clear();
                     existing_attr.value.push_str(val);
                 } else {
-                    // TODO
-                    let ns = None;
-                    // we have overloaded the style namespace to accumulate style attributes without a `style` block
-                    if ns == Some("style") {
-                        // todo: need to accumulate style attributes into a single style
-                        //
-                        // element.
-                    } else {
-                        element.attrs.push(Attribute {
-                            name: qual_name(name, ns),
-                            value: val.to_string(),
-                        });
-
-                        // TODO
-                        if name == "style" {
-                            let doc = BlitzDocument::document();
-                            element.flush_style_attribute(doc.guard());
-                        }
-                    }
+                    element.attrs.push(Attribute {
+                        name: qual_name(name, None),
+                        value: val.to_string(),
+                    });
+                }
+
+                // individual declarations go through `style()` instead, which edits the parsed
+                // block without re-parsing the whole attribute
+                if name == "style" {
+                    let doc = BlitzDocument::document();
+                    element.flush_style_attribute(doc.guard());
                 }
             }
         }
@@ -103,13 +99,158 @@ impl Element {
 
                 // FIXME: check namespace
                 element.attrs.retain(|attr| attr.name.local != *name);
+
+                if name == "style" {
+                    let doc = BlitzDocument::document();
+                    element.flush_style_attribute(doc.guard());
+                }
             }
         }
     }
 
-    pub fn remove(&self) {
+    #[doc = "The `getAttribute()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Element/getAttribute)"]
+    pub fn get_attribute(&self, name: &str) -> Option<String> {
+        let doc = BlitzDocument::document();
+        let element = doc.get_node(self.node_id())?.element_data()?;
+
+        // FIXME: check namespace
+        element
+            .attrs()
+            .iter()
+            .find(|attr| attr.name.local == *name)
+            .map(|attr| attr.value.clone())
+    }
+
+    #[doc = "Getter for the `classList` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Element/classList)"]
+    pub fn class_list(&self) -> DomTokenList {
+        DomTokenList::new(self.clone())
+    }
+
+    #[doc = "Getter for the `style` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/style)"]
+    pub fn style(&self) -> CssStyleDeclaration {
+        CssStyleDeclaration::new(self.clone())
+    }
+
+    #[doc = "Getter for the `tabIndex` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/tabIndex)"]
+    pub fn tab_index(&self) -> i32 {
+        match self.parsed_tab_index() {
+            Some(tab_index) => tab_index,
+            None if self.is_focusable_by_default() => 0,
+            None => -1,
+        }
+    }
+
+    #[doc = "The `focus()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/focus)"]
+    pub fn focus(&self) {
+        if !self.is_focusable() {
+            return;
+        }
+
+        let doc = BlitzDocument::document_mut();
+        let previous = doc.get_focussed_node_id();
+        if previous == Some(self.node_id()) {
+            return;
+        }
+        doc.set_focus_to(self.node_id());
+
+        dispatch_focus_change(previous.map(Element::from).as_ref(), Some(self));
+    }
+
+    #[doc = "The `blur()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/blur)"]
+    pub fn blur(&self) {
         let doc = BlitzDocument::document_mut();
-        doc.remove_node(self.node_id());
+        if doc.get_focussed_node_id() != Some(self.node_id()) {
+            return;
+        }
+        doc.clear_focus();
+
+        dispatch_focus_change(Some(self), None);
+    }
+
+    fn parsed_tab_index(&self) -> Option<i32> {
+        self.get_attribute("tabindex")
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    /// Whether the element can receive focus: any element with a valid `tabindex`, and the form
+    /// controls and links, as long as it isn't a disabled form control and is being rendered.
+    pub fn is_focusable(&self) -> bool {
+        (self.parsed_tab_index().is_some() || self.is_focusable_by_default())
+            && !self.is_disabled_control()
+            && self.is_being_rendered()
+    }
+
+    /// Whether the element is focusable without a `tabindex`.
+    fn is_focusable_by_default(&self) -> bool {
+        let doc = BlitzDocument::document();
+        let Some(element) = doc
+            .get_node(self.node_id())
+            .and_then(|node| node.element_data())
+        else {
+            return false;
+        };
+
+        match element.name.local.as_ref() {
+            "input" => self.get_attribute("type").as_deref() != Some("hidden"),
+            "button" | "select" | "textarea" => true,
+            "a" => self.get_attribute("href").is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_disabled_control(&self) -> bool {
+        let doc = BlitzDocument::document();
+        doc.get_node(self.node_id())
+            .and_then(|node| node.element_data())
+            .is_some_and(|element| {
+                matches!(
+                    element.name.local.as_ref(),
+                    "input" | "button" | "select" | "textarea"
+                ) && element.attr(local_name!("disabled")).is_some()
+            })
+    }
+
+    /// Whether the element is in the document, and neither it nor any of its ancestors is
+    /// `hidden` or has `display: none`. The `hidden` attribute is checked by itself, as the
+    /// computed display is only updated when the document is next styled.
+    fn is_being_rendered(&self) -> bool {
+        let doc = BlitzDocument::document();
+        let root_id = doc.root_node().id;
+        let mut next_node_id = Some(self.node_id());
+        while let Some(node_id) = next_node_id {
+            let Some(node) = doc.get_node(node_id) else {
+                return false;
+            };
+            let hidden = node
+                .element_data()
+                .is_some_and(|element| element.attr(local_name!("hidden")).is_some());
+            if hidden || matches!(node.display_outer, DisplayOuter::None) {
+                return false;
+            }
+            if node_id == root_id {
+                return true;
+            }
+            next_node_id = node.parent;
+        }
+        false
+    }
+}
+
+impl EventTarget for Element {
+    fn event_target_id(&self) -> EventTargetId {
+        EventTargetId::Node(self.node_id())
     }
 }
 
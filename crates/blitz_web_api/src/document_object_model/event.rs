@@ -1 +1,472 @@
-pub struct Event {}
+use super::{
+    blitz_document::BlitzDocument, element::Element, event_listener::EventListeners,
+    event_target::EventTargetId, node::Node,
+};
+use std::{any::Any, cell::Cell, fmt::Debug, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct EventInit {
+    pub bubbles: bool,
+    pub cancelable: bool,
+}
+
+impl EventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[doc = "Change the `bubbles` field of this object."]
+    pub fn set_bubbles(&mut self, val: bool) {
+        self.bubbles = val;
+    }
+
+    #[doc = "Change the `cancelable` field of this object."]
+    pub fn set_cancelable(&mut self, val: bool) {
+        self.cancelable = val;
+    }
+}
+
+struct EventInner {
+    type_: String,
+    bubbles: bool,
+    cancelable: bool,
+    target: Cell<Option<EventTargetId>>,
+    current_target: Cell<Option<EventTargetId>>,
+    event_phase: Cell<u16>,
+    propagation_stopped: Cell<bool>,
+    immediate_propagation_stopped: Cell<bool>,
+    default_prevented: Cell<bool>,
+    in_passive_listener: Cell<bool>,
+    // the init dictionaries of the event interfaces this event implements,
+    // e.g. `MouseEventInit` and `PointerEventInit` for a `PointerEvent`
+    details: Vec<Rc<dyn Any>>,
+}
+
+impl Debug for EventInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Event")
+            .field("type", &self.type_)
+            .field("bubbles", &self.bubbles)
+            .field("cancelable", &self.cancelable)
+            .field("target", &self.target.get())
+            .field("event_phase", &self.event_phase.get())
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Event(Rc<EventInner>);
+
+impl Event {
+    pub const NONE: u16 = 0;
+    pub const CAPTURING_PHASE: u16 = 1;
+    pub const AT_TARGET: u16 = 2;
+    pub const BUBBLING_PHASE: u16 = 3;
+
+    #[doc = "The `new Event(..)` constructor, creating a new instance of `Event`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/Event)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_event_init_dict(type_, &EventInit::new())
+    }
+
+    #[doc = "The `new Event(..)` constructor, creating a new instance of `Event`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/Event)"]
+    pub fn new_with_event_init_dict(type_: &str, event_init_dict: &EventInit) -> Self {
+        Self::new_with_details(type_, event_init_dict, Vec::new())
+    }
+
+    /// Creates an event that carries the init dictionaries of a more specific event interface.
+    pub(super) fn new_with_details(
+        type_: &str,
+        event_init_dict: &EventInit,
+        details: Vec<Rc<dyn Any>>,
+    ) -> Self {
+        Self(Rc::new(EventInner {
+            type_: type_.to_string(),
+            bubbles: event_init_dict.bubbles,
+            cancelable: event_init_dict.cancelable,
+            target: Cell::new(None),
+            current_target: Cell::new(None),
+            event_phase: Cell::new(Self::NONE),
+            propagation_stopped: Cell::new(false),
+            immediate_propagation_stopped: Cell::new(false),
+            default_prevented: Cell::new(false),
+            in_passive_listener: Cell::new(false),
+            details,
+        }))
+    }
+
+    /// The init dictionary of type `T` this event was created with, if any.
+    pub(super) fn detail<T: Any>(&self) -> Option<&T> {
+        self.0
+            .details
+            .iter()
+            .find_map(|detail| detail.downcast_ref::<T>())
+    }
+
+    #[doc = "Getter for the `type` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/type)"]
+    pub fn type_(&self) -> String {
+        self.0.type_.clone()
+    }
+
+    #[doc = "Getter for the `target` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/target)"]
+    pub fn target(&self) -> Option<Element> {
+        Self::element(self.0.target.get())
+    }
+
+    #[doc = "Getter for the `currentTarget` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/currentTarget)"]
+    pub fn current_target(&self) -> Option<Element> {
+        Self::element(self.0.current_target.get())
+    }
+
+    /// The object this event was dispatched to, which unlike [`target`](Self::target) can also
+    /// be the document or the window.
+    pub fn target_id(&self) -> Option<EventTargetId> {
+        self.0.target.get()
+    }
+
+    /// The object whose listeners are currently invoked, which unlike
+    /// [`current_target`](Self::current_target) can also be the document or the window.
+    pub fn current_target_id(&self) -> Option<EventTargetId> {
+        self.0.current_target.get()
+    }
+
+    fn element(target: Option<EventTargetId>) -> Option<Element> {
+        match target? {
+            EventTargetId::Node(node_id) => Element::try_from(Node::from(node_id)).ok(),
+            EventTargetId::Window => None,
+        }
+    }
+
+    #[doc = "Getter for the `eventPhase` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/eventPhase)"]
+    pub fn event_phase(&self) -> u16 {
+        self.0.event_phase.get()
+    }
+
+    #[doc = "Getter for the `bubbles` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/bubbles)"]
+    pub fn bubbles(&self) -> bool {
+        self.0.bubbles
+    }
+
+    #[doc = "Getter for the `cancelable` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/cancelable)"]
+    pub fn cancelable(&self) -> bool {
+        self.0.cancelable
+    }
+
+    #[doc = "Getter for the `defaultPrevented` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/defaultPrevented)"]
+    pub fn default_prevented(&self) -> bool {
+        self.0.default_prevented.get()
+    }
+
+    #[doc = "Getter for the `cancelBubble` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/cancelBubble)"]
+    pub fn cancel_bubble(&self) -> bool {
+        self.0.propagation_stopped.get()
+    }
+
+    #[doc = "The `preventDefault()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault)"]
+    pub fn prevent_default(&self) {
+        if self.0.cancelable && !self.0.in_passive_listener.get() {
+            self.0.default_prevented.set(true);
+        }
+    }
+
+    #[doc = "The `stopPropagation()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/stopPropagation)"]
+    pub fn stop_propagation(&self) {
+        self.0.propagation_stopped.set(true);
+    }
+
+    #[doc = "The `stopImmediatePropagation()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Event/stopImmediatePropagation)"]
+    pub fn stop_immediate_propagation(&self) {
+        self.0.propagation_stopped.set(true);
+        self.0.immediate_propagation_stopped.set(true);
+    }
+
+    /// Whether `stopImmediatePropagation()` has been called, meaning no further listeners,
+    /// including the remaining ones on the current target, should be invoked.
+    pub fn immediate_propagation_stopped(&self) -> bool {
+        self.0.immediate_propagation_stopped.get()
+    }
+
+    /// Listeners registered as passive can't cancel the event.
+    pub(super) fn set_in_passive_listener(&self, passive: bool) {
+        self.0.in_passive_listener.set(passive);
+    }
+
+    /// Dispatches this event to the listeners registered on `target` and its ancestors, up to
+    /// the document and the window.
+    ///
+    /// Returns `false` if a listener called `prevent_default()`.
+    pub(super) fn dispatch(&self, target: EventTargetId) -> bool {
+        let type_ = self.type_();
+        self.dispatch_with(target, |current_target, capture| {
+            for listener in EventListeners::get(current_target, &type_, capture) {
+                if self.immediate_propagation_stopped() {
+                    break;
+                }
+                listener.invoke(self);
+            }
+        })
+    }
+
+    /// Walks `target` and its ancestors through the capture, target and bubble phases.
+    ///
+    /// `invoke` is called once per listener list: with `true` for the capturing listeners
+    /// and `false` for the bubbling listeners of the current target. It is not called again
+    /// once a listener has stopped propagation, and the bubble phase only runs if the event
+    /// [`bubbles`](Self::bubbles).
+    fn dispatch_with(
+        &self,
+        target: EventTargetId,
+        mut invoke: impl FnMut(EventTargetId, bool),
+    ) -> bool {
+        let inner = &self.0;
+        inner.target.set(Some(target));
+
+        // [target, parent, ..., document, window]
+        let path = Self::path(target);
+        let ancestors = &path[1..];
+
+        for &current_target in ancestors.iter().rev() {
+            if inner.propagation_stopped.get() {
+                break;
+            }
+            self.enter(current_target, Self::CAPTURING_PHASE);
+            invoke(current_target, true);
+        }
+
+        if !inner.propagation_stopped.get() {
+            self.enter(target, Self::AT_TARGET);
+            invoke(target, true);
+            // stopPropagation() still lets the other listeners of the current target run
+            if !inner.immediate_propagation_stopped.get() {
+                invoke(target, false);
+            }
+        }
+
+        if inner.bubbles {
+            for &current_target in ancestors {
+                if inner.propagation_stopped.get() {
+                    break;
+                }
+                self.enter(current_target, Self::BUBBLING_PHASE);
+                invoke(current_target, false);
+            }
+        }
+
+        // the event can be dispatched again, e.g. when it is re-fired from a handler
+        inner.event_phase.set(Self::NONE);
+        inner.current_target.set(None);
+        inner.propagation_stopped.set(false);
+        inner.immediate_propagation_stopped.set(false);
+
+        !inner.default_prevented.get()
+    }
+
+    fn enter(&self, current_target: EventTargetId, phase: u16) {
+        self.0.current_target.set(Some(current_target));
+        self.0.event_phase.set(phase);
+    }
+
+    /// The ancestors of `target`, starting with `target` itself.
+    ///
+    /// The window is only part of the path of nodes that are connected to the document.
+    fn path(target: EventTargetId) -> Vec<EventTargetId> {
+        let EventTargetId::Node(node_id) = target else {
+            return vec![target];
+        };
+
+        let doc = BlitzDocument::document();
+        let mut path = vec![target];
+        let mut next = doc.get_node(node_id).and_then(|node| node.parent);
+        let mut root = node_id;
+
+        while let Some(node_id) = next {
+            let Some(node) = doc.get_node(node_id) else {
+                break;
+            };
+            path.push(EventTargetId::Node(node_id));
+            root = node_id;
+            next = node.parent;
+        }
+
+        if root == doc.root_node().id {
+            path.push(EventTargetId::Window);
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::{window, AddEventListenerOptions, EventTarget, ListenerId};
+    use std::cell::RefCell;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    /// `<html><div><button></button></div></html>`, with `<html>` attached to the document.
+    fn tree() -> (Element, Element) {
+        BlitzDocument::set_test_document();
+        let document = window().document().clone();
+        let html = Node::from(BlitzDocument::document().root_element().id);
+        let div = document.create_element_ns(None, "div");
+        let button = document.create_element_ns(None, "button");
+        html.insert_before(&div, None);
+        div.insert_before(&button, None);
+        (div, button)
+    }
+
+    /// Adds a listener that logs `name` along with the phase it was invoked in, then calls `f`.
+    fn listen(
+        log: &Log,
+        target: &impl EventTarget,
+        name: &'static str,
+        capture: bool,
+        f: impl Fn(&Event) + 'static,
+    ) -> ListenerId {
+        let log = Rc::clone(log);
+        let mut options = AddEventListenerOptions::new();
+        options.set_capture(capture);
+        target.add_event_listener_with_callback_and_add_event_listener_options(
+            "click",
+            move |event| {
+                let phase = match event.event_phase() {
+                    Event::CAPTURING_PHASE => "capture",
+                    Event::AT_TARGET => "target",
+                    Event::BUBBLING_PHASE => "bubble",
+                    _ => unreachable!(),
+                };
+                log.borrow_mut().push(format!("{name} {phase}"));
+                f(&event);
+            },
+            &options,
+        )
+    }
+
+    fn click(bubbles: bool, cancelable: bool) -> Event {
+        Event::new_with_event_init_dict(
+            "click",
+            &EventInit {
+                bubbles,
+                cancelable,
+            },
+        )
+    }
+
+    #[test]
+    fn listeners_run_in_capture_target_bubble_order() {
+        let (div, button) = tree();
+        let log = Log::default();
+        listen(&log, &window(), "window", false, |_| {});
+        listen(&log, &div, "div", false, |_| {});
+        // at the target, capturing listeners run first even if added last
+        listen(&log, &button, "button", false, |_| {});
+        listen(&log, &button, "button (capture)", true, |_| {});
+        listen(&log, &div, "div (capture)", true, |_| {});
+        listen(&log, &window(), "window (capture)", true, |_| {});
+
+        assert!(button.dispatch_event(&click(true, false)));
+        assert_eq!(
+            *log.borrow(),
+            [
+                "window (capture) capture",
+                "div (capture) capture",
+                "button (capture) target",
+                "button target",
+                "div bubble",
+                "window bubble",
+            ]
+        );
+    }
+
+    #[test]
+    fn non_bubbling_events_stop_at_the_target() {
+        let (div, button) = tree();
+        let log = Log::default();
+        listen(&log, &div, "div (capture)", true, |_| {});
+        listen(&log, &button, "button", false, |_| {});
+        listen(&log, &div, "div", false, |_| {});
+
+        let event = click(false, false);
+        button.dispatch_event(&event);
+        assert_eq!(*log.borrow(), ["div (capture) capture", "button target"]);
+        // the event can be dispatched again
+        assert_eq!(event.event_phase(), Event::NONE);
+        assert!(event.current_target_id().is_none());
+    }
+
+    #[test]
+    fn stop_propagation_finishes_the_current_target() {
+        let (div, button) = tree();
+        let log = Log::default();
+        listen(&log, &div, "first", true, |event| event.stop_propagation());
+        listen(&log, &div, "second", true, |_| {});
+        listen(&log, &button, "button", false, |_| {});
+        listen(&log, &window(), "window", false, |_| {});
+
+        button.dispatch_event(&click(true, false));
+        assert_eq!(*log.borrow(), ["first capture", "second capture"]);
+    }
+
+    #[test]
+    fn stop_immediate_propagation_skips_remaining_listeners() {
+        let (div, button) = tree();
+        let log = Log::default();
+        listen(&log, &button, "first", false, |event| {
+            event.stop_immediate_propagation()
+        });
+        listen(&log, &button, "second", false, |_| {});
+        listen(&log, &div, "div", false, |_| {});
+
+        button.dispatch_event(&click(true, false));
+        assert_eq!(*log.borrow(), ["first target"]);
+    }
+
+    #[test]
+    fn dispatch_returns_whether_the_default_was_prevented() {
+        let (div, button) = tree();
+        let log = Log::default();
+        listen(&log, &div, "div", false, |event| event.prevent_default());
+
+        assert!(!button.dispatch_event(&click(true, true)));
+        // only cancelable events can be canceled
+        assert!(button.dispatch_event(&click(true, false)));
+    }
+
+    #[test]
+    fn detached_nodes_have_no_window_in_their_path() {
+        let (div, button) = tree();
+        let log = Log::default();
+        listen(&log, &window(), "window", false, |_| {});
+        listen(&log, &div, "div", false, |_| {});
+        div.remove();
+
+        button.dispatch_event(&click(true, false));
+        assert_eq!(*log.borrow(), ["div bubble"]);
+    }
+}
@@ -1,3 +1,12 @@
+use super::{
+    blitz_document::BlitzDocument, event::Event, event_target::EventTargetId, node::NodeId,
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
 pub trait EventListener<T> {
     fn handle_event(&self, event: T);
 }
@@ -10,3 +19,354 @@ where
         self(event)
     }
 }
+
+#[derive(Debug, Clone, Default)]
+pub struct AddEventListenerOptions {
+    pub capture: bool,
+    pub once: bool,
+    pub passive: bool,
+}
+
+impl AddEventListenerOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[doc = "Change the `capture` field of this object."]
+    pub fn set_capture(&mut self, val: bool) {
+        self.capture = val;
+    }
+
+    #[doc = "Change the `once` field of this object."]
+    pub fn set_once(&mut self, val: bool) {
+        self.once = val;
+    }
+
+    #[doc = "Change the `passive` field of this object."]
+    pub fn set_passive(&mut self, val: bool) {
+        self.passive = val;
+    }
+}
+
+/// Identifies a registered event listener, so it can be removed again.
+///
+/// Rust closures can't be compared, so unlike on the web, listeners are removed by the id
+/// they were registered under rather than by the callback itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(u64);
+
+type Callback = Rc<RefCell<dyn FnMut(Event)>>;
+
+#[derive(Clone)]
+pub(super) struct Listener {
+    id: ListenerId,
+    callback: Callback,
+    capture: bool,
+    once: bool,
+    passive: bool,
+    // set once removed, so a listener removed mid-dispatch is not invoked anymore
+    removed: Rc<Cell<bool>>,
+}
+
+impl Listener {
+    /// Invokes the listener with `event`, unless it has been removed in the meantime.
+    pub(super) fn invoke(&self, event: &Event) {
+        if self.removed.get() {
+            return;
+        }
+        if self.once {
+            EventListeners::remove(self.id);
+        }
+
+        // a listener that (indirectly) dispatches an event to itself is not invoked again
+        let Ok(mut callback) = self.callback.try_borrow_mut() else {
+            return;
+        };
+        event.set_in_passive_listener(self.passive);
+        callback(event.clone());
+        event.set_in_passive_listener(false);
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    next_id: u64,
+    listeners: HashMap<(EventTargetId, String), Vec<Listener>>,
+    keys: HashMap<ListenerId, (EventTargetId, String)>,
+}
+
+thread_local! {
+    static LISTENERS: RefCell<Registry> = Default::default();
+}
+
+/// The side table of the event listeners attached to each event target, keyed by target and
+/// event type.
+pub(super) struct EventListeners;
+
+impl EventListeners {
+    pub(super) fn add(
+        target: EventTargetId,
+        type_: &str,
+        callback: impl FnMut(Event) + 'static,
+        options: &AddEventListenerOptions,
+    ) -> ListenerId {
+        LISTENERS.with_borrow_mut(|registry| {
+            let id = ListenerId(registry.next_id);
+            registry.next_id += 1;
+
+            let key = (target, type_.to_string());
+            registry.keys.insert(id, key.clone());
+            registry.listeners.entry(key).or_default().push(Listener {
+                id,
+                callback: Rc::new(RefCell::new(callback)),
+                capture: options.capture,
+                once: options.once,
+                passive: options.passive,
+                removed: Rc::new(Cell::new(false)),
+            });
+
+            id
+        })
+    }
+
+    pub(super) fn remove(id: ListenerId) {
+        LISTENERS.with_borrow_mut(|registry| {
+            let Some(key) = registry.keys.remove(&id) else {
+                return;
+            };
+            if let Some(listeners) = registry.listeners.get_mut(&key) {
+                listeners.retain(|listener| {
+                    let keep = listener.id != id;
+                    if !keep {
+                        listener.removed.set(true);
+                    }
+                    keep
+                });
+                if listeners.is_empty() {
+                    registry.listeners.remove(&key);
+                }
+            }
+        });
+    }
+
+    /// The capturing or bubbling listeners of `type_` on `target`, in registration order.
+    ///
+    /// This is a snapshot, so listeners added while the event is dispatched to the node
+    /// will not be invoked for it.
+    pub(super) fn get(target: EventTargetId, type_: &str, capture: bool) -> Vec<Listener> {
+        LISTENERS.with_borrow(|registry| {
+            registry
+                .listeners
+                .get(&(target, type_.to_string()))
+                .map(|listeners| {
+                    listeners
+                        .iter()
+                        .filter(|listener| listener.capture == capture)
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+    }
+
+    /// Drops the listeners of `node_id` and all of its descendants, which is done when the
+    /// nodes are dropped from the document.
+    pub(super) fn remove_subtree(node_id: NodeId) {
+        let doc = BlitzDocument::document();
+        let mut stack = vec![node_id];
+        let mut node_ids = HashSet::new();
+        while let Some(node_id) = stack.pop() {
+            node_ids.insert(node_id);
+            if let Some(node) = doc.get_node(node_id) {
+                stack.extend(node.children.iter().copied());
+            }
+        }
+
+        LISTENERS.with_borrow_mut(|registry| {
+            if registry.listeners.is_empty() {
+                return;
+            }
+            registry.listeners.retain(|(target, _), listeners| {
+                let keep = match target {
+                    EventTargetId::Node(node_id) => !node_ids.contains(node_id),
+                    EventTargetId::Window => true,
+                };
+                if !keep {
+                    for listener in listeners {
+                        listener.removed.set(true);
+                        registry.keys.remove(&listener.id);
+                    }
+                }
+                keep
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::EventInit;
+
+    const CAPTURE: AddEventListenerOptions = AddEventListenerOptions {
+        capture: true,
+        once: false,
+        passive: false,
+    };
+    const BUBBLE: AddEventListenerOptions = AddEventListenerOptions {
+        capture: false,
+        once: false,
+        passive: false,
+    };
+    const ONCE: AddEventListenerOptions = AddEventListenerOptions {
+        capture: false,
+        once: true,
+        passive: false,
+    };
+    const PASSIVE: AddEventListenerOptions = AddEventListenerOptions {
+        capture: false,
+        once: false,
+        passive: true,
+    };
+
+    type Log = Rc<RefCell<Vec<&'static str>>>;
+
+    /// Adds a listener to `target` that pushes `name` to `log`.
+    fn add(
+        log: &Log,
+        target: EventTargetId,
+        type_: &str,
+        name: &'static str,
+        options: &AddEventListenerOptions,
+    ) -> ListenerId {
+        let log = Rc::clone(log);
+        EventListeners::add(target, type_, move |_| log.borrow_mut().push(name), options)
+    }
+
+    fn invoke_all(target: EventTargetId, event: &Event, capture: bool) {
+        for listener in EventListeners::get(target, &event.type_(), capture) {
+            listener.invoke(event);
+        }
+    }
+
+    #[test]
+    fn listeners_are_kept_per_target_and_type_in_order() {
+        let target = EventTargetId::Node(1);
+        let log = Log::default();
+        add(&log, target, "click", "a", &BUBBLE);
+        add(&log, target, "click", "b", &BUBBLE);
+        add(&log, target, "click", "capture", &CAPTURE);
+        add(&log, target, "input", "input", &BUBBLE);
+        add(&log, EventTargetId::Node(2), "click", "other", &BUBBLE);
+        add(&log, EventTargetId::Window, "click", "window", &BUBBLE);
+
+        let event = Event::new("click");
+        invoke_all(target, &event, false);
+        assert_eq!(*log.borrow(), ["a", "b"]);
+
+        invoke_all(target, &event, true);
+        assert_eq!(*log.borrow(), ["a", "b", "capture"]);
+    }
+
+    #[test]
+    fn removed_listeners_are_not_returned() {
+        let target = EventTargetId::Node(1);
+        let log = Log::default();
+        let a = add(&log, target, "click", "a", &BUBBLE);
+        add(&log, target, "click", "b", &BUBBLE);
+
+        EventListeners::remove(a);
+        // removing a listener again does nothing
+        EventListeners::remove(a);
+
+        invoke_all(target, &Event::new("click"), false);
+        assert_eq!(*log.borrow(), ["b"]);
+    }
+
+    #[test]
+    fn once_listeners_are_removed_when_invoked() {
+        let target = EventTargetId::Node(1);
+        let log = Log::default();
+        add(&log, target, "click", "once", &ONCE);
+        add(&log, target, "click", "always", &BUBBLE);
+
+        let event = Event::new("click");
+        invoke_all(target, &event, false);
+        invoke_all(target, &event, false);
+        assert_eq!(*log.borrow(), ["once", "always", "always"]);
+        assert_eq!(EventListeners::get(target, "click", false).len(), 1);
+    }
+
+    #[test]
+    fn passive_listeners_cannot_prevent_default() {
+        let target = EventTargetId::Node(1);
+        let init = EventInit {
+            bubbles: true,
+            cancelable: true,
+        };
+        let prevent_default = |event: Event| event.prevent_default();
+
+        EventListeners::add(target, "wheel", prevent_default, &PASSIVE);
+        let event = Event::new_with_event_init_dict("wheel", &init);
+        invoke_all(target, &event, false);
+        assert!(!event.default_prevented());
+
+        EventListeners::add(target, "wheel", prevent_default, &BUBBLE);
+        invoke_all(target, &event, false);
+        assert!(event.default_prevented());
+    }
+
+    #[test]
+    fn listeners_removed_during_dispatch_are_not_invoked() {
+        let target = EventTargetId::Node(1);
+        let log = Log::default();
+        let second = Rc::new(Cell::new(None));
+
+        EventListeners::add(
+            target,
+            "click",
+            {
+                let log = Rc::clone(&log);
+                let second = Rc::clone(&second);
+                move |_| {
+                    log.borrow_mut().push("first");
+                    EventListeners::remove(second.get().unwrap());
+                    // added while dispatching, so only invoked for the next event
+                    add(&log, target, "click", "added", &BUBBLE);
+                }
+            },
+            &ONCE,
+        );
+        second.set(Some(add(&log, target, "click", "second", &BUBBLE)));
+
+        invoke_all(target, &Event::new("click"), false);
+        assert_eq!(*log.borrow(), ["first"]);
+
+        invoke_all(target, &Event::new("click"), false);
+        assert_eq!(*log.borrow(), ["first", "added"]);
+    }
+
+    #[test]
+    fn listeners_of_dropped_nodes_are_removed() {
+        use crate::dom::{window, EventTarget};
+
+        BlitzDocument::set_test_document();
+        let document = window().document().clone();
+        let parent = document.create_element_ns(None, "div");
+        let child = document.create_element_ns(None, "span");
+        parent.insert_before(&child, None);
+        let on_parent = parent.add_event_listener_with_callback("click", |_| {});
+        let on_child = child.add_event_listener_with_callback("click", |_| {});
+        let registered = |id| LISTENERS.with_borrow(|registry| registry.keys.contains_key(&id));
+
+        // a removed node may be inserted again, so it keeps its listeners
+        child.remove();
+        assert!(registered(on_child));
+        parent.insert_before(&child, None);
+
+        parent.remove_and_drop();
+        assert!(!registered(on_parent));
+        assert!(!registered(on_child));
+        assert!(EventListeners::get(child.event_target_id(), "click", false).is_empty());
+    }
+}
@@ -1,20 +1,84 @@
-use super::event_listener::EventListener;
+use super::{
+    event::Event,
+    event_listener::{AddEventListenerOptions, EventListener, EventListeners, ListenerId},
+    node::NodeId,
+};
 
-struct EventTarget {}
+/// Identifies the object an event listener is registered on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventTargetId {
+    Node(NodeId),
+    Window,
+}
+
+/// An object that can receive events and have listeners for them: a node, an element, the
+/// document or the window.
+pub trait EventTarget {
+    fn event_target_id(&self) -> EventTargetId;
 
-impl EventTarget {
     #[doc = "The `addEventListener()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener)"]
-    pub fn add_event_listener_with_callback<T>(&self, r#type: &str, listener: impl Fn(T)) {}
+    fn add_event_listener_with_callback(
+        &self,
+        type_: &str,
+        listener: impl FnMut(Event) + 'static,
+    ) -> ListenerId
+    where
+        Self: Sized,
+    {
+        self.add_event_listener_with_callback_and_add_event_listener_options(
+            type_,
+            listener,
+            &AddEventListenerOptions::new(),
+        )
+    }
+
+    #[doc = "The `addEventListener()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener)"]
+    fn add_event_listener_with_callback_and_add_event_listener_options(
+        &self,
+        type_: &str,
+        listener: impl FnMut(Event) + 'static,
+        options: &AddEventListenerOptions,
+    ) -> ListenerId
+    where
+        Self: Sized,
+    {
+        EventListeners::add(self.event_target_id(), type_, listener, options)
+    }
 
     #[doc = "The `addEventListener()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener)"]
-    pub fn add_event_listener_with_event_listener<T>(
+    fn add_event_listener_with_event_listener(
         &self,
-        r#type: &str,
-        listener: impl EventListener<T>,
-    ) {
+        type_: &str,
+        listener: impl EventListener<Event> + 'static,
+    ) -> ListenerId
+    where
+        Self: Sized,
+    {
+        self.add_event_listener_with_callback(type_, move |event| listener.handle_event(event))
+    }
+
+    #[doc = "The `removeEventListener()` method."]
+    #[doc = ""]
+    #[doc = "Listeners are removed by the [`ListenerId`] they were added under, as Rust closures"]
+    #[doc = "can't be compared. Removing a listener that was already removed does nothing."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/removeEventListener)"]
+    fn remove_event_listener(&self, listener: ListenerId) {
+        EventListeners::remove(listener);
+    }
+
+    #[doc = "The `dispatchEvent()` method."]
+    #[doc = ""]
+    #[doc = "Returns `false` if the event is cancelable and a listener called `prevent_default()`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/dispatchEvent)"]
+    fn dispatch_event(&self, event: &Event) -> bool {
+        event.dispatch(self.event_target_id())
     }
 }
@@ -0,0 +1,106 @@
+use super::{
+    element::Element,
+    event::{Event, EventInit},
+    event_target::EventTarget,
+};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct FocusEventInit {
+    pub event: EventInit,
+    pub related_target: Option<Element>,
+}
+
+impl FocusEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FocusEvent(Event);
+
+impl FocusEvent {
+    #[doc = "The `new FocusEvent(..)` constructor, creating a new instance of `FocusEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FocusEvent/FocusEvent)"]
+    pub fn new(type_arg: &str) -> Self {
+        Self::new_with_focus_event_init_dict(type_arg, &FocusEventInit::new())
+    }
+
+    #[doc = "The `new FocusEvent(..)` constructor, creating a new instance of `FocusEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FocusEvent/FocusEvent)"]
+    pub fn new_with_focus_event_init_dict(
+        type_arg: &str,
+        focus_event_init_dict: &FocusEventInit,
+    ) -> Self {
+        Self(Event::new_with_details(
+            type_arg,
+            &focus_event_init_dict.event,
+            vec![Rc::new(focus_event_init_dict.clone())],
+        ))
+    }
+
+    #[doc = "Getter for the `relatedTarget` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FocusEvent/relatedTarget)"]
+    pub fn related_target(&self) -> Option<Element> {
+        self.0
+            .detail::<FocusEventInit>()
+            .and_then(|init| init.related_target.clone())
+    }
+}
+
+impl Deref for FocusEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for FocusEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FocusEvent> for Event {
+    fn from(value: FocusEvent) -> Self {
+        value.0
+    }
+}
+
+/// Fires the events of focus moving from `previous` to `next`: `blur` and `focusout` at the
+/// element losing focus, then `focus` and `focusin` at the element gaining it.
+pub(super) fn dispatch_focus_change(previous: Option<&Element>, next: Option<&Element>) {
+    let init = |bubbles, related_target: Option<&Element>| FocusEventInit {
+        event: EventInit {
+            bubbles,
+            cancelable: false,
+        },
+        related_target: related_target.cloned(),
+    };
+
+    if let Some(previous) = previous {
+        previous.dispatch_event(&FocusEvent::new_with_focus_event_init_dict(
+            "blur",
+            &init(false, next),
+        ));
+        previous.dispatch_event(&FocusEvent::new_with_focus_event_init_dict(
+            "focusout",
+            &init(true, next),
+        ));
+    }
+    if let Some(next) = next {
+        next.dispatch_event(&FocusEvent::new_with_focus_event_init_dict(
+            "focus",
+            &init(false, previous),
+        ));
+        next.dispatch_event(&FocusEvent::new_with_focus_event_init_dict(
+            "focusin",
+            &init(true, previous),
+        ));
+    }
+}
@@ -0,0 +1,86 @@
+use super::event::{Event, EventInit};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct InputEventInit {
+    pub event: EventInit,
+    pub data: Option<String>,
+    pub input_type: String,
+    pub is_composing: bool,
+}
+
+impl InputEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InputEvent(Event);
+
+impl InputEvent {
+    #[doc = "The `new InputEvent(..)` constructor, creating a new instance of `InputEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/InputEvent/InputEvent)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_event_init_dict(type_, &InputEventInit::new())
+    }
+
+    #[doc = "The `new InputEvent(..)` constructor, creating a new instance of `InputEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/InputEvent/InputEvent)"]
+    pub fn new_with_event_init_dict(type_: &str, event_init_dict: &InputEventInit) -> Self {
+        Self(Event::new_with_details(
+            type_,
+            &event_init_dict.event,
+            vec![Rc::new(event_init_dict.clone())],
+        ))
+    }
+
+    fn init(&self) -> Option<&InputEventInit> {
+        self.0.detail()
+    }
+
+    #[doc = "Getter for the `data` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/InputEvent/data)"]
+    pub fn data(&self) -> Option<String> {
+        self.init().and_then(|init| init.data.clone())
+    }
+
+    #[doc = "Getter for the `inputType` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/InputEvent/inputType)"]
+    pub fn input_type(&self) -> String {
+        self.init()
+            .map(|init| init.input_type.clone())
+            .unwrap_or_default()
+    }
+
+    #[doc = "Getter for the `isComposing` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/InputEvent/isComposing)"]
+    pub fn is_composing(&self) -> bool {
+        self.init().is_some_and(|init| init.is_composing)
+    }
+}
+
+impl Deref for InputEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for InputEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<InputEvent> for Event {
+    fn from(value: InputEvent) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,156 @@
+use super::event::{Event, EventInit};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct KeyboardEventInit {
+    pub event: EventInit,
+    pub key: String,
+    pub code: String,
+    pub location: u32,
+    pub repeat: bool,
+    pub is_composing: bool,
+    pub ctrl_key: bool,
+    pub shift_key: bool,
+    pub alt_key: bool,
+    pub meta_key: bool,
+}
+
+impl KeyboardEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyboardEvent(Event);
+
+impl KeyboardEvent {
+    pub const DOM_KEY_LOCATION_STANDARD: u32 = 0;
+    pub const DOM_KEY_LOCATION_LEFT: u32 = 1;
+    pub const DOM_KEY_LOCATION_RIGHT: u32 = 2;
+    pub const DOM_KEY_LOCATION_NUMPAD: u32 = 3;
+
+    #[doc = "The `new KeyboardEvent(..)` constructor, creating a new instance of `KeyboardEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/KeyboardEvent)"]
+    pub fn new(type_arg: &str) -> Self {
+        Self::new_with_keyboard_event_init_dict(type_arg, &KeyboardEventInit::new())
+    }
+
+    #[doc = "The `new KeyboardEvent(..)` constructor, creating a new instance of `KeyboardEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/KeyboardEvent)"]
+    pub fn new_with_keyboard_event_init_dict(
+        type_arg: &str,
+        keyboard_event_init_dict: &KeyboardEventInit,
+    ) -> Self {
+        Self(Event::new_with_details(
+            type_arg,
+            &keyboard_event_init_dict.event,
+            vec![Rc::new(keyboard_event_init_dict.clone())],
+        ))
+    }
+
+    fn init(&self) -> Option<&KeyboardEventInit> {
+        self.0.detail()
+    }
+
+    #[doc = "Getter for the `key` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key)"]
+    pub fn key(&self) -> String {
+        self.init().map(|init| init.key.clone()).unwrap_or_default()
+    }
+
+    #[doc = "Getter for the `code` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/code)"]
+    pub fn code(&self) -> String {
+        self.init()
+            .map(|init| init.code.clone())
+            .unwrap_or_default()
+    }
+
+    #[doc = "Getter for the `location` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/location)"]
+    pub fn location(&self) -> u32 {
+        self.init()
+            .map_or(Self::DOM_KEY_LOCATION_STANDARD, |init| init.location)
+    }
+
+    #[doc = "Getter for the `repeat` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/repeat)"]
+    pub fn repeat(&self) -> bool {
+        self.init().is_some_and(|init| init.repeat)
+    }
+
+    #[doc = "Getter for the `isComposing` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/isComposing)"]
+    pub fn is_composing(&self) -> bool {
+        self.init().is_some_and(|init| init.is_composing)
+    }
+
+    #[doc = "Getter for the `ctrlKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/ctrlKey)"]
+    pub fn ctrl_key(&self) -> bool {
+        self.init().is_some_and(|init| init.ctrl_key)
+    }
+
+    #[doc = "Getter for the `shiftKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/shiftKey)"]
+    pub fn shift_key(&self) -> bool {
+        self.init().is_some_and(|init| init.shift_key)
+    }
+
+    #[doc = "Getter for the `altKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/altKey)"]
+    pub fn alt_key(&self) -> bool {
+        self.init().is_some_and(|init| init.alt_key)
+    }
+
+    #[doc = "Getter for the `metaKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/metaKey)"]
+    pub fn meta_key(&self) -> bool {
+        self.init().is_some_and(|init| init.meta_key)
+    }
+
+    #[doc = "The `getModifierState()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/getModifierState)"]
+    pub fn get_modifier_state(&self, key: &str) -> bool {
+        match key {
+            "Control" => self.ctrl_key(),
+            "Shift" => self.shift_key(),
+            "Alt" => self.alt_key(),
+            "Meta" => self.meta_key(),
+            _ => false,
+        }
+    }
+}
+
+impl Deref for KeyboardEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for KeyboardEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<KeyboardEvent> for Event {
+    fn from(value: KeyboardEvent) -> Self {
+        value.0
+    }
+}
@@ -1,20 +1,45 @@
 mod blitz_document;
 mod comment;
+mod composition_event;
+mod css_style_declaration;
+mod custom_event;
 mod document;
+mod dom_token_list;
 mod element;
 mod event;
 mod event_listener;
 mod event_target;
+mod focus_event;
+mod input_event;
+mod keyboard_event;
+mod mouse_event;
 mod node;
+mod pointer_event;
+mod property;
 mod text;
+mod wheel_event;
 mod window;
 
 pub use blitz_document::*;
 pub use comment::*;
+pub use composition_event::*;
+pub use css_style_declaration::*;
+pub use custom_event::*;
 pub use document::*;
+pub use dom_token_list::*;
 pub use element::*;
+pub use event::*;
+pub use event_listener::{AddEventListenerOptions, EventListener, ListenerId};
+pub use event_target::*;
+pub use focus_event::*;
+pub use input_event::*;
+pub use keyboard_event::*;
+pub use mouse_event::*;
 pub use node::*;
+pub use pointer_event::*;
+pub use property::*;
 pub use text::*;
+pub use wheel_event::*;
 pub use window::*;
 
 use std::cell::RefCell;
@@ -24,6 +49,8 @@ use thiserror::Error;
 pub enum DomError {
     #[error("The {0} is not of {1} type")]
     Type(&'static str, &'static str),
+    #[error("The {0} property is not supported")]
+    UnsupportedProperty(String),
 }
 
 thread_local! {
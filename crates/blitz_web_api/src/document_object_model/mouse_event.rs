@@ -0,0 +1,178 @@
+use super::{
+    element::Element,
+    event::{Event, EventInit},
+};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct MouseEventInit {
+    pub event: EventInit,
+    pub screen_x: i32,
+    pub screen_y: i32,
+    pub client_x: i32,
+    pub client_y: i32,
+    pub button: i16,
+    pub buttons: u16,
+    pub ctrl_key: bool,
+    pub shift_key: bool,
+    pub alt_key: bool,
+    pub meta_key: bool,
+    pub related_target: Option<Element>,
+}
+
+impl MouseEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MouseEvent(Event);
+
+impl MouseEvent {
+    #[doc = "The `new MouseEvent(..)` constructor, creating a new instance of `MouseEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/MouseEvent)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_mouse_event_init_dict(type_, &MouseEventInit::new())
+    }
+
+    #[doc = "The `new MouseEvent(..)` constructor, creating a new instance of `MouseEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/MouseEvent)"]
+    pub fn new_with_mouse_event_init_dict(type_: &str, event_init_dict: &MouseEventInit) -> Self {
+        Self(Event::new_with_details(
+            type_,
+            &event_init_dict.event,
+            vec![Rc::new(event_init_dict.clone())],
+        ))
+    }
+
+    fn init(&self) -> Option<&MouseEventInit> {
+        self.0.detail()
+    }
+
+    #[doc = "Getter for the `screenX` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/screenX)"]
+    pub fn screen_x(&self) -> i32 {
+        self.init().map_or(0, |init| init.screen_x)
+    }
+
+    #[doc = "Getter for the `screenY` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/screenY)"]
+    pub fn screen_y(&self) -> i32 {
+        self.init().map_or(0, |init| init.screen_y)
+    }
+
+    #[doc = "Getter for the `clientX` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/clientX)"]
+    pub fn client_x(&self) -> i32 {
+        self.init().map_or(0, |init| init.client_x)
+    }
+
+    #[doc = "Getter for the `clientY` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/clientY)"]
+    pub fn client_y(&self) -> i32 {
+        self.init().map_or(0, |init| init.client_y)
+    }
+
+    #[doc = "Getter for the `x` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/x)"]
+    pub fn x(&self) -> i32 {
+        self.client_x()
+    }
+
+    #[doc = "Getter for the `y` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/y)"]
+    pub fn y(&self) -> i32 {
+        self.client_y()
+    }
+
+    #[doc = "Getter for the `button` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/button)"]
+    pub fn button(&self) -> i16 {
+        self.init().map_or(0, |init| init.button)
+    }
+
+    #[doc = "Getter for the `buttons` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/buttons)"]
+    pub fn buttons(&self) -> u16 {
+        self.init().map_or(0, |init| init.buttons)
+    }
+
+    #[doc = "Getter for the `ctrlKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/ctrlKey)"]
+    pub fn ctrl_key(&self) -> bool {
+        self.init().is_some_and(|init| init.ctrl_key)
+    }
+
+    #[doc = "Getter for the `shiftKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/shiftKey)"]
+    pub fn shift_key(&self) -> bool {
+        self.init().is_some_and(|init| init.shift_key)
+    }
+
+    #[doc = "Getter for the `altKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/altKey)"]
+    pub fn alt_key(&self) -> bool {
+        self.init().is_some_and(|init| init.alt_key)
+    }
+
+    #[doc = "Getter for the `metaKey` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/metaKey)"]
+    pub fn meta_key(&self) -> bool {
+        self.init().is_some_and(|init| init.meta_key)
+    }
+
+    #[doc = "Getter for the `relatedTarget` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/relatedTarget)"]
+    pub fn related_target(&self) -> Option<Element> {
+        self.init().and_then(|init| init.related_target.clone())
+    }
+
+    #[doc = "The `getModifierState()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/getModifierState)"]
+    pub fn get_modifier_state(&self, key_arg: &str) -> bool {
+        match key_arg {
+            "Control" => self.ctrl_key(),
+            "Shift" => self.shift_key(),
+            "Alt" => self.alt_key(),
+            "Meta" => self.meta_key(),
+            _ => false,
+        }
+    }
+}
+
+impl Deref for MouseEvent {
+    type Target = Event;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for MouseEvent {
+    fn from(value: Event) -> Self {
+        Self(value)
+    }
+}
+
+impl From<MouseEvent> for Event {
+    fn from(value: MouseEvent) -> Self {
+        value.0
+    }
+}
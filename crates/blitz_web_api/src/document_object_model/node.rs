@@ -1,4 +1,8 @@
-use super::blitz_document::BlitzDocument;
+use super::{
+    blitz_document::BlitzDocument,
+    event_listener::EventListeners,
+    event_target::{EventTarget, EventTargetId},
+};
 use blitz_dom::{local_name, NodeData};
 
 pub(super) type NodeId = usize;
@@ -65,11 +69,50 @@ impl Node {
         }
     }
 
+    #[doc = "Getter for the `isConnected` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Node/isConnected)"]
+    pub fn is_connected(&self) -> bool {
+        let doc = BlitzDocument::document();
+        Node(doc.root_node().id).contains(Some(self))
+    }
+
+    #[doc = "The `contains()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Node/contains)"]
+    pub fn contains(&self, other: Option<&Node>) -> bool {
+        let doc = BlitzDocument::document();
+        let mut node_id = other.map(Node::node_id);
+        while let Some(id) = node_id {
+            if id == self.node_id() {
+                return true;
+            }
+            node_id = doc.get_node(id).and_then(|node| node.parent);
+        }
+        false
+    }
+
     #[doc = "The `insertBefore()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Node/insertBefore)"]
     pub fn insert_before(&self, new_node: &Node, reference_node: Option<&Node>) {
+        // inserting a node before itself leaves it where it is
+        if reference_node.is_some_and(|node| node.node_id() == new_node.node_id()) {
+            return;
+        }
+
         let doc = BlitzDocument::document_mut();
+
+        // Inserting a node that is already in the tree moves it: detach it from its current
+        // parent first so it doesn't end up listed twice.
+        let old_parent = doc
+            .get_node(new_node.node_id())
+            .and_then(|node| node.parent);
+        if let Some(old_parent) = old_parent {
+            let old_parent = doc.get_node_mut(old_parent).unwrap();
+            old_parent.children.retain(|id| *id != new_node.node_id());
+        }
+
         if let Some(reference_node) = reference_node {
             // TODO Verify that reference_node's parent is self
             doc.insert_before(reference_node.node_id(), &[new_node.node_id()]);
@@ -88,10 +131,32 @@ impl Node {
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Node/removeChild)"]
     pub fn remove_child(&self, child: &Node) -> Option<Node> {
-        BlitzDocument::document_mut().remove_node(child.node_id());
+        child.remove();
         Some(child.clone())
     }
 
+    #[doc = "The `remove()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Element/remove)"]
+    pub fn remove(&self) {
+        // the node may be inserted again, so its event listeners are kept
+        BlitzDocument::document_mut().remove_node(self.node_id());
+    }
+
+    /// Removes the node from its parent and drops it and its descendants from the document,
+    /// along with their event listeners. Unlike after [`remove`](Self::remove), the nodes can't
+    /// be inserted again and must not be used anymore.
+    pub fn remove_and_drop(&self) {
+        EventListeners::remove_subtree(self.node_id());
+
+        let focussed = BlitzDocument::document().get_focussed_node_id();
+        let doc = BlitzDocument::document_mut();
+        if focussed.is_some_and(|focussed| self.contains(Some(&Node(focussed)))) {
+            doc.clear_focus();
+        }
+        doc.remove_and_drop_node(self.node_id());
+    }
+
     #[doc = "Setter for the `textContent` field of this object."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Node/textContent)"]
@@ -101,7 +166,19 @@ impl Node {
 
         let text = match node.raw_dom_data {
             NodeData::Text(ref mut text) => text,
-            // todo: otherwise this is basically element.textContent which is a bit different - need to parse as html
+            // on an element, the text replaces all of its children. It is never parsed as HTML,
+            // which is what `innerHTML` is for
+            NodeData::Element(_) => {
+                for child in node.children.clone() {
+                    Node(child).remove();
+                }
+                if !value.is_empty() {
+                    let text = BlitzDocument::document_mut().create_text_node(value);
+                    self.insert_before(&Node(text), None);
+                }
+                Self::maybe_update_style_node(BlitzDocument::document_mut(), Some(self.node_id()));
+                return;
+            }
             _ => return,
         };
 
@@ -115,8 +192,83 @@ impl Node {
     }
 }
 
+impl EventTarget for Node {
+    fn event_target_id(&self) -> EventTargetId {
+        EventTargetId::Node(self.node_id())
+    }
+}
+
 impl From<NodeId> for Node {
     fn from(value: NodeId) -> Self {
         Self(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::window;
+
+    fn children(parent: &Node) -> Vec<NodeId> {
+        BlitzDocument::document()
+            .get_node(parent.node_id())
+            .unwrap()
+            .children
+            .clone()
+    }
+
+    #[test]
+    fn insert_before_moves_nodes_and_ignores_itself_as_reference() {
+        BlitzDocument::set_test_document();
+        let document = window().document().clone();
+        let parent = document.create_element_ns(None, "div");
+        let a = document.create_text_node("a");
+        let b = document.create_text_node("b");
+        parent.insert_before(&a, None);
+        parent.insert_before(&b, None);
+        assert_eq!(children(&parent), [a.node_id(), b.node_id()]);
+
+        parent.insert_before(&b, Some(&b));
+        assert_eq!(children(&parent), [a.node_id(), b.node_id()]);
+
+        parent.insert_before(&b, Some(&a));
+        assert_eq!(children(&parent), [b.node_id(), a.node_id()]);
+        assert_eq!(a.parent_node().unwrap().node_id(), parent.node_id());
+    }
+
+    #[test]
+    fn contains_includes_the_node_and_its_descendants() {
+        BlitzDocument::set_test_document();
+        let document = window().document().clone();
+        let parent = document.create_element_ns(None, "div");
+        let child = document.create_element_ns(None, "span");
+        let text = document.create_text_node("a");
+        parent.insert_before(&child, None);
+        child.insert_before(&text, None);
+
+        assert!(parent.contains(Some(&parent)));
+        assert!(parent.contains(Some(&text)));
+        assert!(!text.contains(Some(&parent)));
+        assert!(!parent.contains(None));
+
+        child.remove();
+        assert!(!parent.contains(Some(&text)));
+    }
+
+    #[test]
+    fn is_connected_sees_whether_the_root_contains_the_node() {
+        BlitzDocument::set_test_document();
+        let document = window().document().clone();
+        let root = Node::from(BlitzDocument::document().root_node().id);
+        let parent = document.create_element_ns(None, "div");
+        let child = document.create_element_ns(None, "span");
+        parent.insert_before(&child, None);
+        assert!(!child.is_connected());
+
+        root.insert_before(&parent, None);
+        assert!(child.is_connected());
+
+        parent.remove();
+        assert!(!child.is_connected());
+    }
+}
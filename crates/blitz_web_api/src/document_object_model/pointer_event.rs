@@ -0,0 +1,132 @@
+use super::{
+    event::Event,
+    mouse_event::{MouseEvent, MouseEventInit},
+};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct PointerEventInit {
+    pub mouse: MouseEventInit,
+    pub pointer_id: i32,
+    pub width: i32,
+    pub height: i32,
+    pub pressure: f32,
+    pub tilt_x: i32,
+    pub tilt_y: i32,
+    pub pointer_type: String,
+    pub is_primary: bool,
+}
+
+impl PointerEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PointerEvent(MouseEvent);
+
+impl PointerEvent {
+    #[doc = "The `new PointerEvent(..)` constructor, creating a new instance of `PointerEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/PointerEvent)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_event_init_dict(type_, &PointerEventInit::new())
+    }
+
+    #[doc = "The `new PointerEvent(..)` constructor, creating a new instance of `PointerEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/PointerEvent)"]
+    pub fn new_with_event_init_dict(type_: &str, event_init_dict: &PointerEventInit) -> Self {
+        Self(MouseEvent::from(Event::new_with_details(
+            type_,
+            &event_init_dict.mouse.event,
+            vec![
+                Rc::new(event_init_dict.mouse.clone()),
+                Rc::new(event_init_dict.clone()),
+            ],
+        )))
+    }
+
+    fn init(&self) -> Option<&PointerEventInit> {
+        self.detail()
+    }
+
+    #[doc = "Getter for the `pointerId` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/pointerId)"]
+    pub fn pointer_id(&self) -> i32 {
+        self.init().map_or(0, |init| init.pointer_id)
+    }
+
+    #[doc = "Getter for the `width` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/width)"]
+    pub fn width(&self) -> i32 {
+        self.init().map_or(1, |init| init.width)
+    }
+
+    #[doc = "Getter for the `height` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/height)"]
+    pub fn height(&self) -> i32 {
+        self.init().map_or(1, |init| init.height)
+    }
+
+    #[doc = "Getter for the `pressure` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/pressure)"]
+    pub fn pressure(&self) -> f32 {
+        self.init().map_or(0.0, |init| init.pressure)
+    }
+
+    #[doc = "Getter for the `tiltX` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/tiltX)"]
+    pub fn tilt_x(&self) -> i32 {
+        self.init().map_or(0, |init| init.tilt_x)
+    }
+
+    #[doc = "Getter for the `tiltY` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/tiltY)"]
+    pub fn tilt_y(&self) -> i32 {
+        self.init().map_or(0, |init| init.tilt_y)
+    }
+
+    #[doc = "Getter for the `pointerType` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/pointerType)"]
+    pub fn pointer_type(&self) -> String {
+        self.init()
+            .map(|init| init.pointer_type.clone())
+            .unwrap_or_default()
+    }
+
+    #[doc = "Getter for the `isPrimary` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/isPrimary)"]
+    pub fn is_primary(&self) -> bool {
+        self.init().is_some_and(|init| init.is_primary)
+    }
+}
+
+impl Deref for PointerEvent {
+    type Target = MouseEvent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for PointerEvent {
+    fn from(value: Event) -> Self {
+        Self(MouseEvent::from(value))
+    }
+}
+
+impl From<PointerEvent> for Event {
+    fn from(value: PointerEvent) -> Self {
+        Event::from(value.0)
+    }
+}
@@ -0,0 +1,493 @@
+use super::{blitz_document::BlitzDocument, document::qual_name, element::Element, DomError};
+use blitz_dom::{
+    local_name,
+    node::{Attribute, NodeSpecificData},
+    ElementNodeData, LocalName, NodeData, RestyleHint,
+};
+
+/// A value that can be assigned to a DOM property with [`Element::set_property`].
+///
+/// Properties coerce the value to the type they expect, the way JavaScript does, so
+/// `prop:checked="on"` checks a checkbox and `prop:value=3` sets the text `"3"`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl PropertyValue {
+    /// The value converted to a boolean, following JavaScript's truthiness rules.
+    pub fn as_bool(&self) -> bool {
+        match self {
+            PropertyValue::Null => false,
+            PropertyValue::Bool(value) => *value,
+            PropertyValue::Number(value) => *value != 0.0 && !value.is_nan(),
+            PropertyValue::String(value) => !value.is_empty(),
+        }
+    }
+
+    /// The value converted to a number. Strings that don't parse become `NaN`.
+    pub fn as_number(&self) -> f64 {
+        match self {
+            PropertyValue::Null => 0.0,
+            PropertyValue::Bool(value) => *value as u8 as f64,
+            PropertyValue::Number(value) => *value,
+            PropertyValue::String(value) => value.trim().parse().unwrap_or(f64::NAN),
+        }
+    }
+
+    /// The value converted to a string. `Null` becomes the empty string, as it does for the
+    /// `value` of form controls.
+    pub fn as_string(&self) -> String {
+        match self {
+            PropertyValue::Null => String::new(),
+            PropertyValue::Bool(value) => value.to_string(),
+            PropertyValue::Number(value) => value.to_string(),
+            PropertyValue::String(value) => value.clone(),
+        }
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        PropertyValue::Bool(value)
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        PropertyValue::String(value)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        PropertyValue::String(value.to_owned())
+    }
+}
+
+impl<T> From<Option<T>> for PropertyValue
+where
+    T: Into<PropertyValue>,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or(PropertyValue::Null, Into::into)
+    }
+}
+
+macro_rules! number_property_value {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for PropertyValue {
+                fn from(value: $ty) -> Self {
+                    PropertyValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+number_property_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl Element {
+    /// Sets a DOM property by its JavaScript name, converting the value to the property's type.
+    ///
+    /// Supports `value`, `checked`, `disabled` and `selectedIndex`.
+    pub fn set_property(&self, name: &str, value: &PropertyValue) -> Result<(), DomError> {
+        match name {
+            "value" => self.set_value(&value.as_string()),
+            "checked" => self.set_checked(value.as_bool()),
+            "disabled" => self.set_disabled(value.as_bool()),
+            "selectedIndex" => {
+                let index = value.as_number();
+                self.set_selected_index(if index.is_finite() { index as i32 } else { 0 })
+            }
+            _ => return Err(DomError::UnsupportedProperty(name.to_owned())),
+        }
+        Ok(())
+    }
+
+    #[doc = "Getter for the `value` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/value)"]
+    pub fn value(&self) -> String {
+        let doc = BlitzDocument::document();
+        let Some(element) = doc
+            .get_node(self.node_id())
+            .and_then(|node| node.element_data())
+        else {
+            return String::new();
+        };
+
+        match element.text_input_data() {
+            Some(input_data) => input_data.editor.text().to_string(),
+            None => element
+                .attr(local_name!("value"))
+                .unwrap_or_default()
+                .to_owned(),
+        }
+    }
+
+    #[doc = "Setter for the `value` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/value)"]
+    pub fn set_value(&self, value: &str) {
+        let doc = BlitzDocument::document_mut();
+        let node = &mut doc.nodes[self.node_id()];
+        let NodeData::Element(ref mut element) = node.raw_dom_data else {
+            return;
+        };
+
+        match element.text_input_data_mut() {
+            // resetting the editor to the text it already holds would move the cursor
+            Some(input_data) if input_data.editor.text() == value => {}
+            Some(input_data) => {
+                input_data.set_text(&mut doc.font_ctx, &mut doc.layout_ctx, value);
+            }
+            // the editor is created from the `value` attribute when the element is first laid
+            // out, so until then that is where the value lives
+            None => set_initial_attribute(element, "value", Some(value)),
+        }
+    }
+
+    #[doc = "Getter for the `checked` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/checked)"]
+    pub fn checked(&self) -> bool {
+        let doc = BlitzDocument::document();
+        doc.get_node(self.node_id())
+            .and_then(|node| node.element_data())
+            .is_some_and(|element| {
+                element
+                    .checkbox_input_checked()
+                    .unwrap_or_else(|| element.attr(local_name!("checked")).is_some())
+            })
+    }
+
+    #[doc = "Setter for the `checked` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/checked)"]
+    pub fn set_checked(&self, checked: bool) {
+        let doc = BlitzDocument::document_mut();
+        let node = &mut doc.nodes[self.node_id()];
+
+        let stylo_element_data = &mut *node.stylo_element_data.borrow_mut();
+        if let Some(data) = stylo_element_data {
+            data.hint |= RestyleHint::RESTYLE_SELF;
+        }
+
+        let NodeData::Element(ref mut element) = node.raw_dom_data else {
+            return;
+        };
+        match element.checkbox_input_checked_mut() {
+            Some(checked_mut) => *checked_mut = checked,
+            // as with `value`, the checkbox state is created from the attribute later
+            None => set_initial_attribute(element, "checked", checked.then_some("")),
+        }
+    }
+
+    #[doc = "Getter for the `disabled` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/disabled)"]
+    pub fn disabled(&self) -> bool {
+        self.get_attribute("disabled").is_some()
+    }
+
+    #[doc = "Setter for the `disabled` field of this object."]
+    #[doc = ""]
+    #[doc = "Unlike `checked` and `value`, `disabled` has no state apart from its attribute: it"]
+    #[doc = "reflects the `disabled` attribute, which is also what focus and `:disabled` look at, so"]
+    #[doc = "setting it adds or removes that attribute."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/disabled)"]
+    pub fn set_disabled(&self, disabled: bool) {
+        if disabled {
+            self.set_attribute("disabled", "");
+        } else {
+            self.remove_attribute("disabled");
+        }
+    }
+
+    #[doc = "Getter for the `selectedIndex` field of this object."]
+    #[doc = ""]
+    #[doc = "A drop-down (a `<select>` without `multiple` and with a `size` of at most 1) always"]
+    #[doc = "shows an option: the last one marked `selected`, or else the first one that isn't"]
+    #[doc = "disabled. A list box may have no option selected, in which case this is -1."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLSelectElement/selectedIndex)"]
+    pub fn selected_index(&self) -> i32 {
+        let options = self.options();
+        let index = if self.is_drop_down() {
+            // once the selection has been set, the drop-down may be left without an option
+            let selection_set = options
+                .iter()
+                .any(|option| option.set_selectedness().is_some());
+            options
+                .iter()
+                .rposition(Element::option_selectedness)
+                .or_else(|| {
+                    let fallback = options
+                        .iter()
+                        .position(|option| !option.is_disabled_option());
+                    fallback.filter(|_| !selection_set)
+                })
+        } else {
+            options.iter().position(Element::option_selectedness)
+        };
+        index.map_or(-1, |index| index as i32)
+    }
+
+    #[doc = "Setter for the `selectedIndex` field of this object."]
+    #[doc = ""]
+    #[doc = "Like in browsers, this changes which options are selected without touching their"]
+    #[doc = "`selected` attributes. An index that is out of range leaves no option selected."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLSelectElement/selectedIndex)"]
+    pub fn set_selected_index(&self, index: i32) {
+        let options = self.options();
+        let doc = BlitzDocument::document_mut();
+        for (i, option) in options.iter().enumerate() {
+            let node = &mut doc.nodes[option.node_id()];
+
+            let stylo_element_data = &mut *node.stylo_element_data.borrow_mut();
+            if let Some(data) = stylo_element_data {
+                data.hint |= RestyleHint::RESTYLE_SELF;
+            }
+
+            // blitz has no state for options, so their selectedness is kept in the same
+            // `NodeSpecificData` as a checkbox's checkedness, which `:checked` also matches
+            if let NodeData::Element(ref mut element) = node.raw_dom_data {
+                element.node_specific_data = NodeSpecificData::CheckboxInput(i as i32 == index);
+            }
+        }
+    }
+
+    /// Whether this `<select>` is shown as a drop-down rather than as a list box.
+    fn is_drop_down(&self) -> bool {
+        let size = self
+            .get_attribute("size")
+            .and_then(|size| size.trim().parse::<u32>().ok());
+        self.get_attribute("multiple").is_none() && size.unwrap_or(1) <= 1
+    }
+
+    /// Whether this `<option>` is selected: as last set through its `<select>`, or else as its
+    /// `selected` attribute says.
+    fn option_selectedness(&self) -> bool {
+        self.set_selectedness()
+            .unwrap_or_else(|| self.get_attribute("selected").is_some())
+    }
+
+    /// The selectedness of this `<option>` if its `<select>` has had its selection set. Unlike
+    /// `checked`, this mustn't change the `selected` attribute, which only sets the default.
+    fn set_selectedness(&self) -> Option<bool> {
+        let doc = BlitzDocument::document();
+        doc.get_node(self.node_id())
+            .and_then(|node| node.element_data())
+            .and_then(|element| element.checkbox_input_checked())
+    }
+
+    /// Whether this `<option>`, or the `<optgroup>` it is in, is disabled.
+    fn is_disabled_option(&self) -> bool {
+        if self.get_attribute("disabled").is_some() {
+            return true;
+        }
+        let doc = BlitzDocument::document();
+        doc.get_node(self.node_id())
+            .and_then(|node| node.parent)
+            .and_then(|parent| doc.get_node(parent))
+            .and_then(|parent| parent.element_data())
+            .is_some_and(|parent| {
+                parent.name.local == local_name!("optgroup")
+                    && parent.attr(local_name!("disabled")).is_some()
+            })
+    }
+
+    /// The `<option>`s of a `<select>`, including those inside an `<optgroup>`, in tree order.
+    fn options(&self) -> Vec<Element> {
+        let doc = BlitzDocument::document();
+        let Some(node) = doc.get_node(self.node_id()) else {
+            return Vec::new();
+        };
+        if !node
+            .raw_dom_data
+            .is_element_with_tag_name(&local_name!("select"))
+        {
+            return Vec::new();
+        }
+
+        let is_element = |id: usize, name: &LocalName| {
+            doc.get_node(id)
+                .unwrap()
+                .raw_dom_data
+                .is_element_with_tag_name(name)
+        };
+
+        let mut options = Vec::new();
+        for &child_id in &node.children {
+            if is_element(child_id, &local_name!("option")) {
+                options.push(child_id);
+            } else if is_element(child_id, &local_name!("optgroup")) {
+                let group = &doc.get_node(child_id).unwrap().children;
+                options.extend(
+                    group
+                        .iter()
+                        .copied()
+                        .filter(|id| is_element(*id, &local_name!("option"))),
+                );
+            }
+        }
+        options.into_iter().map(Element::from).collect()
+    }
+}
+
+/// Sets or removes the attribute that a form control's state is initialised from, for elements
+/// whose `NodeSpecificData` hasn't been created yet.
+fn set_initial_attribute(element: &mut ElementNodeData, name: &str, value: Option<&str>) {
+    let Some(value) = value else {
+        element.attrs.retain(|attr| attr.name.local != *name);
+        return;
+    };
+
+    let existing_attr = element
+        .attrs
+        .iter_mut()
+        .find(|attr| attr.name.local == *name);
+    if let Some(existing_attr) = existing_attr {
+        existing_attr.value.clear();
+        existing_attr.value.push_str(value);
+    } else {
+        element.attrs.push(Attribute {
+            name: qual_name(name, None),
+            value: value.to_owned(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dom::window;
+
+    fn element(name: &str, attrs: &[(&str, &str)]) -> Element {
+        let el = window().document().create_element_ns(None, name);
+        for (name, value) in attrs {
+            el.set_attribute(name, value);
+        }
+        el
+    }
+
+    /// A `<select>` with an `<option>` for each list of attributes.
+    fn select(attrs: &[(&str, &str)], options: &[Attrs]) -> (Element, Vec<Element>) {
+        BlitzDocument::set_test_document();
+        let select = element("select", attrs);
+        let options = options
+            .iter()
+            .map(|attrs| {
+                let option = element("option", attrs);
+                select.insert_before(&option, None);
+                option
+            })
+            .collect();
+        (select, options)
+    }
+
+    type Attrs = &'static [(&'static str, &'static str)];
+
+    const SELECTED: Attrs = &[("selected", "")];
+    const DISABLED: Attrs = &[("disabled", "")];
+    const MULTIPLE: Attrs = &[("multiple", "")];
+    const LIST_BOX: Attrs = &[("size", "3")];
+
+    #[test]
+    fn selected_index_follows_the_selected_attributes() {
+        #[rustfmt::skip]
+        let cases: &[(Attrs, &[Attrs], i32)] = &[
+            // a drop-down falls back to its first enabled option
+            (&[], &[], -1),
+            (&[], &[&[], &[]], 0),
+            (&[], &[DISABLED, &[], &[]], 1),
+            (&[], &[DISABLED, DISABLED], -1),
+            (&[], &[&[], SELECTED, &[]], 1),
+            (&[], &[SELECTED, &[], SELECTED], 2),
+            (&[("size", "1")], &[&[], &[]], 0),
+            (&[("size", "x")], &[&[], &[]], 0),
+            // a list box may have nothing selected
+            (MULTIPLE, &[&[], &[]], -1),
+            (MULTIPLE, &[&[], SELECTED, SELECTED], 1),
+            (&[("multiple", ""), ("size", "1")], &[&[], &[]], -1),
+            (LIST_BOX, &[&[], &[]], -1),
+            (LIST_BOX, &[SELECTED, &[], SELECTED], 0),
+        ];
+
+        for (attrs, options, expected) in cases {
+            let (select, _) = select(attrs, options);
+            assert_eq!(select.selected_index(), *expected, "{attrs:?} {options:?}");
+        }
+    }
+
+    #[test]
+    fn options_in_a_disabled_group_are_skipped() {
+        let (select, _) = select(&[], &[]);
+        let group = element("optgroup", DISABLED);
+        select.insert_before(&group, None);
+        group.insert_before(&element("option", &[]), None);
+        select.insert_before(&element("option", &[]), None);
+        assert_eq!(select.selected_index(), 1);
+    }
+
+    #[test]
+    fn set_selected_index_keeps_the_selected_attributes() {
+        for attrs in [&[][..], MULTIPLE, LIST_BOX] {
+            let (select, options) = select(attrs, &[SELECTED, &[], &[]]);
+            select.set_selected_index(2);
+            assert_eq!(select.selected_index(), 2, "{attrs:?}");
+            assert!(options[0].get_attribute("selected").is_some());
+            assert!(options[2].get_attribute("selected").is_none());
+
+            // an index out of range deselects everything, even in a drop-down
+            select.set_selected_index(-1);
+            assert_eq!(select.selected_index(), -1, "{attrs:?}");
+
+            select.set_property("selectedIndex", &"1".into()).unwrap();
+            assert_eq!(select.selected_index(), 1, "{attrs:?}");
+        }
+    }
+
+    #[test]
+    fn selectedness_is_dropped_with_the_options() {
+        let (select, options) = select(&[], &[&[], &[]]);
+        select.set_selected_index(-1);
+        let ids: Vec<_> = options.iter().map(|option| option.node_id()).collect();
+        select.remove_and_drop();
+
+        // the new nodes take the ids of the dropped ones, but not their selectedness
+        let select = element("select", &[]);
+        let options = [element("option", &[]), element("option", &[])];
+        for option in &options {
+            select.insert_before(option, None);
+        }
+        let new_ids = [select.node_id(), options[0].node_id(), options[1].node_id()];
+        assert!(ids.iter().all(|id| new_ids.contains(id)));
+        assert_eq!(select.selected_index(), 0);
+    }
+
+    #[test]
+    fn disabled_reflects_the_disabled_attribute() {
+        BlitzDocument::set_test_document();
+        let button = element("button", &[]);
+        button.set_property("disabled", &true.into()).unwrap();
+        assert!(button.disabled());
+        assert_eq!(button.get_attribute("disabled").as_deref(), Some(""));
+
+        button
+            .set_property("disabled", &PropertyValue::Null)
+            .unwrap();
+        assert!(!button.disabled());
+        assert_eq!(button.get_attribute("disabled"), None);
+
+        button.set_attribute("disabled", "disabled");
+        assert!(button.disabled());
+    }
+}
@@ -0,0 +1,103 @@
+use super::{
+    event::Event,
+    mouse_event::{MouseEvent, MouseEventInit},
+};
+use std::{ops::Deref, rc::Rc};
+
+#[derive(Debug, Clone, Default)]
+pub struct WheelEventInit {
+    pub mouse: MouseEventInit,
+    pub delta_x: f64,
+    pub delta_y: f64,
+    pub delta_z: f64,
+    pub delta_mode: u32,
+}
+
+impl WheelEventInit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WheelEvent(MouseEvent);
+
+impl WheelEvent {
+    pub const DOM_DELTA_PIXEL: u32 = 0;
+    pub const DOM_DELTA_LINE: u32 = 1;
+    pub const DOM_DELTA_PAGE: u32 = 2;
+
+    #[doc = "The `new WheelEvent(..)` constructor, creating a new instance of `WheelEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/WheelEvent)"]
+    pub fn new(type_: &str) -> Self {
+        Self::new_with_event_init_dict(type_, &WheelEventInit::new())
+    }
+
+    #[doc = "The `new WheelEvent(..)` constructor, creating a new instance of `WheelEvent`."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/WheelEvent)"]
+    pub fn new_with_event_init_dict(type_: &str, event_init_dict: &WheelEventInit) -> Self {
+        Self(MouseEvent::from(Event::new_with_details(
+            type_,
+            &event_init_dict.mouse.event,
+            vec![
+                Rc::new(event_init_dict.mouse.clone()),
+                Rc::new(event_init_dict.clone()),
+            ],
+        )))
+    }
+
+    fn init(&self) -> Option<&WheelEventInit> {
+        self.detail()
+    }
+
+    #[doc = "Getter for the `deltaX` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/deltaX)"]
+    pub fn delta_x(&self) -> f64 {
+        self.init().map_or(0.0, |init| init.delta_x)
+    }
+
+    #[doc = "Getter for the `deltaY` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/deltaY)"]
+    pub fn delta_y(&self) -> f64 {
+        self.init().map_or(0.0, |init| init.delta_y)
+    }
+
+    #[doc = "Getter for the `deltaZ` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/deltaZ)"]
+    pub fn delta_z(&self) -> f64 {
+        self.init().map_or(0.0, |init| init.delta_z)
+    }
+
+    #[doc = "Getter for the `deltaMode` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WheelEvent/deltaMode)"]
+    pub fn delta_mode(&self) -> u32 {
+        self.init()
+            .map_or(Self::DOM_DELTA_PIXEL, |init| init.delta_mode)
+    }
+}
+
+impl Deref for WheelEvent {
+    type Target = MouseEvent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Event> for WheelEvent {
+    fn from(value: Event) -> Self {
+        Self(MouseEvent::from(value))
+    }
+}
+
+impl From<WheelEvent> for Event {
+    fn from(value: WheelEvent) -> Self {
+        Event::from(value.0)
+    }
+}
@@ -1,4 +1,7 @@
-use super::document::Document;
+use super::{
+    document::Document,
+    event_target::{EventTarget, EventTargetId},
+};
 
 #[derive(Debug, Clone)]
 pub struct Window {
@@ -16,3 +19,9 @@ impl Window {
         &self.doc
     }
 }
+
+impl EventTarget for Window {
+    fn event_target_id(&self) -> EventTargetId {
+        EventTargetId::Window
+    }
+}
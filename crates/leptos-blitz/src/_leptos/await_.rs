@@ -0,0 +1,94 @@
+use super::{into_view::IntoView, suspense_component::Suspense};
+use crate::_tachys::reactive_graph::Suspend;
+use leptos_blitz_macro::{component, view};
+use std::future::Future;
+
+#[component]
+/// Allows you to inline the data loading for an `async` block directly into your view. This is
+/// the equivalent of awaiting the future in a [`Suspend`] inside a [`Suspense`] with no
+/// `fallback`.
+///
+/// Adding `let:{variable name}` to the props makes the data available in the children
+/// that variable name, when resolved.
+/// ```
+/// # use leptos_blitz::prelude::*;
+/// # if false {
+/// async fn fetch_monkeys(monkey: i32) -> i32 {
+///     // do some expensive work
+///     3
+/// }
+///
+/// view! {
+///     <Await
+///         future=fetch_monkeys(3)
+///         let:data
+///     >
+///         <p>{*data} " little monkeys, jumping on the bed."</p>
+///     </Await>
+/// }
+/// # ;
+/// # }
+/// ```
+pub fn Await<T, Fut, Chil, V>(
+    /// A [`Future`] that will the component will `.await` before rendering.
+    future: Fut,
+    /// A function that takes a reference to the resolved data from the `future`
+    /// renders a view.
+    ///
+    /// ## Syntax
+    /// This can be passed in the `view` children of the `<Await/>` by using the
+    /// `let:` syntax to specify the name for the data variable.
+    ///
+    /// ```rust
+    /// # use leptos_blitz::prelude::*;
+    /// # if false {
+    /// # async fn fetch_monkeys(monkey: i32) -> i32 {
+    /// #    3
+    /// # }
+    /// view! {
+    ///     <Await
+    ///         future=fetch_monkeys(3)
+    ///         let:data
+    ///     >
+    ///         <p>{*data} " little monkeys, jumping on the bed."</p>
+    ///     </Await>
+    /// }
+    /// # ;
+    /// # }
+    /// ```
+    /// is the same as
+    ///  ```rust
+    /// # use leptos_blitz::prelude::*;
+    /// # if false {
+    /// # async fn fetch_monkeys(monkey: i32) -> i32 {
+    /// #    3
+    /// # }
+    /// view! {
+    ///     <Await
+    ///         future=fetch_monkeys(3)
+    ///         children=|data| view! {
+    ///           <p>{*data} " little monkeys, jumping on the bed."</p>
+    ///         }
+    ///     />
+    /// }
+    /// # ;
+    /// # }
+    /// ```
+    children: Chil,
+) -> impl IntoView
+where
+    T: Send + 'static,
+    Fut: Future<Output = T> + Send + 'static,
+    Chil: FnOnce(&T) -> V + Send + 'static,
+    V: IntoView + 'static,
+{
+    view! {
+        <Suspense fallback=|| ()>
+            {Suspend::new(async move {
+                let data = future.await;
+                children(&data)
+            })}
+
+        </Suspense>
+    }
+}
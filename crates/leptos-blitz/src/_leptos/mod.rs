@@ -1,2 +1,8 @@
+pub mod await_;
+pub mod children;
+pub mod component;
+pub mod for_loop;
 pub mod into_view;
 pub mod mount;
+pub mod suspense_component;
+pub mod transition;
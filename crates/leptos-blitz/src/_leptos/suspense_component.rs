@@ -0,0 +1,508 @@
+use super::{
+    children::{TypedChildren, ViewFnOnce},
+    into_view::IntoView,
+};
+use crate::_tachys::{
+    html::attribute::Attribute,
+    reactive_graph::{OwnedView, OwnedViewState},
+    ssr::StreamBuilder,
+    view::{
+        add_attr::AddAnyAttr,
+        either::{EitherKeepAlive, EitherKeepAliveState},
+        Mountable, Position, Render, RenderHtml,
+    },
+};
+use either_of::Either;
+use futures::{select, FutureExt};
+use leptos_blitz_macro::component;
+use reactive_graph::{
+    computed::{
+        suspense::{LocalResourceNotifier, SuspenseContext},
+        ArcMemo, ScopedFuture,
+    },
+    effect::RenderEffect,
+    owner::{provide_context, use_context, Owner},
+    signal::ArcRwSignal,
+    traits::{Dispose, Get, Read, Track, With},
+};
+use slotmap::{DefaultKey, SlotMap};
+
+/// If any [`AsyncDerived`](reactive_graph::computed::AsyncDerived) is read in the `children` of
+/// this component, it will show the `fallback` while they are loading. Once all are resolved,
+/// it will render the `children`.
+///
+/// Each time one of the resources is loading again, it will fall back. To keep the current
+/// children instead, use [Transition](crate::prelude::Transition).
+///
+/// Note that the `children` will be rendered initially (in order to capture the fact that
+/// those resources are read under the suspense), so you cannot assume that resources read
+/// synchronously have `Some` value in `children`. However, you can read resources
+/// asynchronously by using [Suspend](crate::prelude::Suspend).
+///
+/// The futures are driven by the document's event loop, and the `children` are swapped in as soon
+/// as the last one resolves.
+///
+/// ```
+/// # use leptos_blitz::prelude::*;
+/// # if false { // don't run in doctests
+/// async fn fetch_cats(how_many: u32) -> Vec<String> { vec![] }
+///
+/// let (cat_count, set_cat_count) = signal::<u32>(1);
+///
+/// let cats = AsyncDerived::new(move || fetch_cats(cat_count.get()));
+///
+/// view! {
+///   <div>
+///     <Suspense fallback=move || view! { <p>"Loading (Suspense Fallback)..."</p> }>
+///       // you can access a resource synchronously
+///       {move || {
+///           cats.get().map(|data| {
+///             data
+///               .into_iter()
+///               .map(|name| view! { <p>{name}</p> })
+///               .collect::<Vec<_>>()
+///           })
+///         }
+///       }
+///       // or you can use `Suspend` to read resources asynchronously
+///       {move || Suspend::new(async move {
+///         cats.await
+///               .into_iter()
+///               .map(|name| view! { <p>{name}</p> })
+///               .collect::<Vec<_>>()
+///       })}
+///     </Suspense>
+///   </div>
+/// }
+/// # ;}
+/// ```
+#[component]
+pub fn Suspense<Chil>(
+    /// A function that returns a fallback that will be shown while resources are still loading.
+    /// By default this is an empty view.
+    #[prop(optional, into)]
+    fallback: ViewFnOnce,
+    /// Children will be rendered once initially to catch any resource reads, then hidden until all
+    /// data have loaded.
+    children: TypedChildren<Chil>,
+) -> impl IntoView
+where
+    Chil: IntoView + Send + 'static,
+{
+    let owner = Owner::new();
+    owner.with(|| {
+        let fallback = fallback.run();
+        let children = children.into_inner()();
+        let tasks = ArcRwSignal::new(SlotMap::<DefaultKey, ()>::new());
+        provide_context(SuspenseContext {
+            tasks: tasks.clone(),
+        });
+        let none_pending = ArcMemo::new(move |_| {
+            tasks.track();
+            tasks.with(SlotMap::is_empty)
+        });
+
+        OwnedView::new(SuspenseBoundary::<false, _, _> {
+            none_pending,
+            fallback,
+            children,
+        })
+    })
+}
+
+pub(crate) struct SuspenseBoundary<const TRANSITION: bool, Fal, Chil> {
+    pub none_pending: ArcMemo<bool>,
+    pub fallback: Fal,
+    pub children: Chil,
+}
+
+impl<const TRANSITION: bool, Fal, Chil> Render for SuspenseBoundary<TRANSITION, Fal, Chil>
+where
+    Fal: Render + Send + 'static,
+    Chil: Render + Send + 'static,
+{
+    type State = RenderEffect<OwnedViewState<EitherKeepAliveState<Chil::State, Fal::State>>>;
+
+    fn build(self) -> Self::State {
+        let mut children = Some(self.children);
+        let mut fallback = Some(self.fallback);
+        let none_pending = self.none_pending;
+        let mut nth_run = 0;
+        let outer_owner = Owner::new();
+
+        RenderEffect::new(move |prev| {
+            // show the fallback if
+            // 1) there are pending futures, and
+            // 2) we are either in a Suspense (not Transition), or it's the first fallback
+            //    (because we initially render the children to register Futures, the "first
+            //    fallback" is probably the 2nd run
+            let show_b = !none_pending.get() && (!TRANSITION || nth_run < 2);
+            nth_run += 1;
+            let this = OwnedView::new_with_owner(
+                EitherKeepAlive {
+                    a: children.take(),
+                    b: fallback.take(),
+                    show_b,
+                },
+                outer_owner.clone(),
+            );
+
+            if let Some(mut state) = prev {
+                this.rebuild(&mut state);
+                state
+            } else {
+                this.build()
+            }
+        })
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let new = self.build();
+        let mut old = std::mem::replace(state, new);
+        old.insert_before_this(state);
+        old.unmount();
+    }
+}
+
+impl<const TRANSITION: bool, Fal, Chil> AddAnyAttr for SuspenseBoundary<TRANSITION, Fal, Chil>
+where
+    Fal: RenderHtml + Send + 'static,
+    Chil: RenderHtml + Send + 'static,
+{
+    type Output<SomeNewAttr: Attribute> =
+        SuspenseBoundary<TRANSITION, Fal, Chil::Output<SomeNewAttr::CloneableOwned>>;
+
+    fn add_any_attr<NewAttr: Attribute>(self, attr: NewAttr) -> Self::Output<NewAttr>
+    where
+        Self::Output<NewAttr>: RenderHtml,
+    {
+        let attr = attr.into_cloneable_owned();
+        let SuspenseBoundary {
+            none_pending,
+            fallback,
+            children,
+        } = self;
+        SuspenseBoundary {
+            none_pending,
+            fallback,
+            children: children.add_any_attr(attr),
+        }
+    }
+}
+
+impl<const TRANSITION: bool, Fal, Chil> RenderHtml for SuspenseBoundary<TRANSITION, Fal, Chil>
+where
+    Fal: RenderHtml + Send + 'static,
+    Chil: RenderHtml + Send + 'static,
+{
+    // i.e., if this is the child of another Suspense during SSR, don't wait for it: it will handle
+    // itself
+    type AsyncOutput = Self;
+
+    const MIN_LENGTH: usize = Chil::MIN_LENGTH;
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn to_html_with_buf(
+        self,
+        buf: &mut String,
+        position: &mut Position,
+        escape: bool,
+        mark_branches: bool,
+    ) {
+        self.fallback
+            .to_html_with_buf(buf, position, escape, mark_branches);
+    }
+
+    fn to_html_async_with_buf<const OUT_OF_ORDER: bool>(
+        mut self,
+        buf: &mut StreamBuilder,
+        position: &mut Position,
+        escape: bool,
+        mark_branches: bool,
+    ) where
+        Self: Sized,
+    {
+        buf.next_id();
+        let suspense_context = use_context::<SuspenseContext>().unwrap();
+        let owner = Owner::current().unwrap();
+
+        // we need to wait for one of two things: either
+        // 1. all tasks are finished loading, or
+        // 2. we read from a local resource, meaning this Suspense can never resolve
+
+        // first, create listener for tasks
+        let tasks = suspense_context.tasks.clone();
+        let (tasks_tx, mut tasks_rx) = futures::channel::oneshot::channel::<()>();
+
+        let mut tasks_tx = Some(tasks_tx);
+
+        // now, create listener for local resources
+        let (local_tx, mut local_rx) = futures::channel::oneshot::channel::<()>();
+        provide_context(LocalResourceNotifier::from(local_tx));
+
+        // walk over the tree of children once to make sure that all resource loads are registered
+        self.children.dry_resolve();
+
+        // check the set of tasks to see if it is empty, now or later
+        let eff = reactive_graph::effect::Effect::new_isomorphic({
+            move |_| {
+                tasks.track();
+                if let Some(tasks) = tasks.try_read() {
+                    if tasks.is_empty() {
+                        if let Some(tx) = tasks_tx.take() {
+                            // If the receiver has dropped, it means the ScopedFuture has already
+                            // dropped, so it doesn't matter if we manage to send this.
+                            _ = tx.send(());
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut fut = Box::pin(ScopedFuture::new(async move {
+            // race the local resource notifier against the set of tasks
+            //
+            // if there are local resources, we just return the fallback immediately
+            //
+            // otherwise, we want to wait for resources to load before trying to resolve the body
+            select! {
+                _ = local_rx => None,
+                _ = tasks_rx => {
+                    let mut children = Box::pin(self.children.resolve().fuse());
+
+                    // we continue racing the children against the "do we have any local
+                    // resources?" Future
+                    select! {
+                        _ = local_rx => None,
+                        children = children => {
+                            // clean up the (now useless) effect
+                            eff.dispose();
+
+                            Some(OwnedView::new_with_owner(children, owner))
+                        }
+                    }
+                }
+            }
+        }));
+        match fut.as_mut().now_or_never() {
+            Some(Some(resolved)) => {
+                Either::<Fal, _>::Right(resolved).to_html_async_with_buf::<OUT_OF_ORDER>(
+                    buf,
+                    position,
+                    escape,
+                    mark_branches,
+                );
+            }
+            Some(None) => {
+                Either::<_, Chil>::Left(self.fallback).to_html_async_with_buf::<OUT_OF_ORDER>(
+                    buf,
+                    position,
+                    escape,
+                    mark_branches,
+                );
+            }
+            None => {
+                let id = buf.clone_id();
+
+                // out-of-order streams immediately push fallback,
+                // wrapped by suspense markers
+                if OUT_OF_ORDER {
+                    let mut fallback_position = *position;
+                    buf.push_fallback(self.fallback, &mut fallback_position, mark_branches);
+                    buf.push_async_out_of_order(fut, position, mark_branches);
+                } else {
+                    buf.push_async({
+                        let mut position = *position;
+                        async move {
+                            let value = match fut.await {
+                                None => Either::Left(self.fallback),
+                                Some(value) => Either::Right(value),
+                            };
+                            let mut builder = StreamBuilder::new(id);
+                            value.to_html_async_with_buf::<OUT_OF_ORDER>(
+                                &mut builder,
+                                &mut position,
+                                escape,
+                                mark_branches,
+                            );
+                            builder.finish().take_chunks()
+                        }
+                    });
+                    *position = Position::NextChild;
+                }
+            }
+        };
+    }
+}
+
+/// A wrapper that prevents [`Suspense`] from waiting for any resource reads that happen inside
+/// `Unsuspend`.
+pub struct Unsuspend<T>(Box<dyn FnOnce() -> T + Send>);
+
+impl<T> Unsuspend<T> {
+    /// Wraps the given function, such that it is not called until all resources are ready.
+    pub fn new(fun: impl FnOnce() -> T + Send + 'static) -> Self {
+        Self(Box::new(fun))
+    }
+}
+
+impl<T> Render for Unsuspend<T>
+where
+    T: Render,
+{
+    type State = T::State;
+
+    fn build(self) -> Self::State {
+        (self.0)().build()
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        (self.0)().rebuild(state);
+    }
+}
+
+impl<T> AddAnyAttr for Unsuspend<T>
+where
+    T: AddAnyAttr + 'static,
+{
+    type Output<SomeNewAttr: Attribute> = Unsuspend<T::Output<SomeNewAttr::CloneableOwned>>;
+
+    fn add_any_attr<NewAttr: Attribute>(self, attr: NewAttr) -> Self::Output<NewAttr>
+    where
+        Self::Output<NewAttr>: RenderHtml,
+    {
+        let attr = attr.into_cloneable_owned();
+        Unsuspend::new(move || (self.0)().add_any_attr(attr))
+    }
+}
+
+impl<T> RenderHtml for Unsuspend<T>
+where
+    T: RenderHtml + 'static,
+{
+    type AsyncOutput = Self;
+
+    const MIN_LENGTH: usize = T::MIN_LENGTH;
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+
+    fn to_html_with_buf(
+        self,
+        buf: &mut String,
+        position: &mut Position,
+        escape: bool,
+        mark_branches: bool,
+    ) {
+        (self.0)().to_html_with_buf(buf, position, escape, mark_branches);
+    }
+
+    fn to_html_async_with_buf<const OUT_OF_ORDER: bool>(
+        self,
+        buf: &mut StreamBuilder,
+        position: &mut Position,
+        escape: bool,
+        mark_branches: bool,
+    ) where
+        Self: Sized,
+    {
+        (self.0)().to_html_async_with_buf::<OUT_OF_ORDER>(buf, position, escape, mark_branches);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{_leptos::mount::mount_to, _leptos_blitz::LeptosDocument, prelude::*};
+    use blitz_web_api::dom::{BlitzDocument, Element, EventTarget, MouseEvent, MouseEventInit};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tokio::{sync::Notify, task::LocalSet};
+
+    /// Lets spawned tasks and queued effects run.
+    async fn settle() {
+        for _ in 0..32 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// The first `<button>` in the document, if it is attached.
+    fn button() -> Option<Element> {
+        let doc = BlitzDocument::document();
+        let mut stack = vec![doc.root_node().id];
+        while let Some(node_id) = stack.pop() {
+            let node = doc.get_node(node_id).unwrap();
+            if node
+                .element_data()
+                .is_some_and(|el| &*el.name.local == "button")
+            {
+                return Some(Element::from(node_id));
+            }
+            stack.extend(node.children.iter().rev());
+        }
+        None
+    }
+
+    fn click(el: &Element) {
+        let mut init = MouseEventInit::new();
+        init.event.set_bubbles(true);
+        el.dispatch_event(&MouseEvent::new_with_mouse_event_init_dict("click", &init).into());
+    }
+
+    #[tokio::test]
+    async fn handlers_keep_working_after_a_reload() {
+        LocalSet::new()
+            .run_until(async {
+                let root = LeptosDocument::install_document(None);
+                let clicks = Arc::new(AtomicUsize::new(0));
+                let loaded = Arc::new(Notify::new());
+                let (version, set_version) = signal(0);
+
+                let (_owner, _mountable) = mount_to(root.into(), {
+                    let clicks = Arc::clone(&clicks);
+                    let loaded = Arc::clone(&loaded);
+                    move || {
+                        let data = AsyncDerived::new(move || {
+                            let version = version.get();
+                            let loaded = Arc::clone(&loaded);
+                            async move {
+                                loaded.notified().await;
+                                version
+                            }
+                        });
+                        view! {
+                            <Suspense fallback=|| "loading">
+                                <button on:click=move |_| {
+                                    clicks.fetch_add(1, Ordering::SeqCst);
+                                }>"click"</button>
+                                {move || Suspend::new(async move { data.await.to_string() })}
+                            </Suspense>
+                        }
+                    }
+                });
+
+                for reload in 0..2 {
+                    if reload > 0 {
+                        set_version.set(reload);
+                    }
+                    settle().await;
+                    assert!(button().is_none(), "the fallback is shown while loading");
+
+                    loaded.notify_one();
+                    settle().await;
+                    click(&button().expect("the children are shown once loaded"));
+                    assert_eq!(clicks.load(Ordering::SeqCst), reload + 1);
+                }
+            })
+            .await;
+    }
+}
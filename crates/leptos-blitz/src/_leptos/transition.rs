@@ -0,0 +1,100 @@
+use super::{
+    children::{TypedChildren, ViewFnOnce},
+    into_view::IntoView,
+    suspense_component::SuspenseBoundary,
+};
+use crate::_tachys::reactive_graph::OwnedView;
+use leptos_blitz_macro::component;
+use reactive_graph::{
+    computed::{suspense::SuspenseContext, ArcMemo},
+    effect::Effect,
+    owner::{provide_context, Owner},
+    signal::ArcRwSignal,
+    traits::{Get, Set, Track, With},
+    wrappers::write::SignalSetter,
+};
+use slotmap::{DefaultKey, SlotMap};
+
+/// If any [`AsyncDerived`](reactive_graph::computed::AsyncDerived) is read in the `children` of
+/// this component, it will show the `fallback` while they are loading. Once all are resolved,
+/// it will render the `children`.
+///
+/// Unlike [`Suspense`](crate::prelude::Suspense), this will not fall
+/// back to the `fallback` state if there are further changes after the initial load: the current
+/// children stay on screen until the new data have loaded.
+///
+/// Note that the `children` will be rendered initially (in order to capture the fact that
+/// those resources are read under the suspense), so you cannot assume that resources read
+/// synchronously have `Some` value in `children`. However, you can read resources
+/// asynchronously by using [Suspend](crate::prelude::Suspend).
+///
+/// ```
+/// # use leptos_blitz::prelude::*;
+/// # if false { // don't run in doctests
+/// async fn fetch_cats(how_many: u32) -> Vec<String> { vec![] }
+///
+/// let (cat_count, set_cat_count) = signal::<u32>(1);
+/// let (pending, set_pending) = signal(false);
+///
+/// let cats = AsyncDerived::new(move || fetch_cats(cat_count.get()));
+///
+/// view! {
+///   <div>
+///     <Transition
+///       fallback=move || view! { <p>"Loading (Suspense Fallback)..."</p> }
+///       set_pending
+///     >
+///       {move || Suspend::new(async move {
+///         cats.await
+///               .into_iter()
+///               .map(|name| view! { <p>{name}</p> })
+///               .collect::<Vec<_>>()
+///       })}
+///     </Transition>
+///   </div>
+/// }
+/// # ;}
+/// ```
+#[component]
+pub fn Transition<Chil>(
+    /// Will be displayed while resources are pending. By default this is the empty view.
+    #[prop(optional, into)]
+    fallback: ViewFnOnce,
+    /// A function that will be called when the component transitions into or out of
+    /// the `pending` state, with its argument indicating whether it is pending (`true`)
+    /// or not pending (`false`).
+    #[prop(optional, into)]
+    set_pending: Option<SignalSetter<bool>>,
+    children: TypedChildren<Chil>,
+) -> impl IntoView
+where
+    Chil: IntoView + Send + 'static,
+{
+    let owner = Owner::new();
+    owner.with(|| {
+        let fallback = fallback.run();
+        let children = children.into_inner()();
+        let tasks = ArcRwSignal::new(SlotMap::<DefaultKey, ()>::new());
+        provide_context(SuspenseContext {
+            tasks: tasks.clone(),
+        });
+        let none_pending = ArcMemo::new(move |_| {
+            tasks.track();
+            tasks.with(SlotMap::is_empty)
+        });
+        if let Some(set_pending) = set_pending {
+            Effect::new_isomorphic({
+                let none_pending = none_pending.clone();
+                move |_| {
+                    set_pending.set(!none_pending.get());
+                }
+            });
+        }
+
+        OwnedView::new(SuspenseBoundary::<true, _, _> {
+            none_pending,
+            fallback,
+            children,
+        })
+    })
+}
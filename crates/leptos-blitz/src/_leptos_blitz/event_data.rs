@@ -0,0 +1,229 @@
+//! Conversions from blitz's [`EventData`] into the DOM event interfaces received by handlers.
+
+use crate::ev::{
+    self, CompositionEvent, CompositionEventInit, CustomEventInit, Event, EventInit, FocusEvent,
+    FocusEventInit, InputEvent, InputEventInit, KeyboardEvent, KeyboardEventInit, MouseEvent,
+    MouseEventInit, ThemeChangeEvent,
+};
+use blitz_dom::events::EventData;
+use blitz_traits::ColorScheme;
+use blitz_web_api::dom;
+use winit::{
+    event::{Ime, KeyEvent, Modifiers, WindowEvent},
+    keyboard::{Key, KeyCode, KeyLocation, NamedKey, PhysicalKey},
+    window::Theme,
+};
+
+/// Builds the typed event for `data`, dispatched under the event name `name`.
+pub(crate) fn dom_event(name: &str, data: &EventData) -> Event {
+    match data {
+        EventData::Click { x, y, mods } => mouse_event(name, *x, *y, mods, None).into(),
+        EventData::KeyPress { event, mods } => keyboard_event(name, event, mods, false).into(),
+        EventData::Ime(Ime::Commit(text)) => {
+            input_event(name, Some(text.clone()), "insertText", false).into()
+        }
+        EventData::Ime(Ime::Preedit(text, _)) => {
+            input_event(name, Some(text.clone()), "insertCompositionText", true).into()
+        }
+        EventData::Ime(_) => input_event(name, None, "insertCompositionText", true).into(),
+        EventData::Hover => Event::new_with_event_init_dict(name, &event_init(name)),
+    }
+}
+
+/// The init dictionary of a trusted event, which bubbles as described by its
+/// [`EventDescriptor`](crate::ev::EventDescriptor).
+pub(crate) fn event_init(name: &str) -> EventInit {
+    EventInit {
+        bubbles: ev::bubbles(name),
+        cancelable: cancelable(name),
+    }
+}
+
+/// Whether the trusted event `name` can be cancelled, as the UI Events specification says.
+///
+/// Events that only report what has already happened, such as `mouseenter`, `focus`, `input` or
+/// `compositionend`, are not.
+fn cancelable(name: &str) -> bool {
+    matches!(
+        name,
+        "click"
+            | "dblclick"
+            | "auxclick"
+            | "contextmenu"
+            | "mousedown"
+            | "mouseup"
+            | "mousemove"
+            | "mouseover"
+            | "mouseout"
+            | "wheel"
+            | "keydown"
+            | "keyup"
+            | "keypress"
+            | "beforeinput"
+            | "compositionstart"
+    )
+}
+
+pub(crate) fn mouse_event(
+    name: &str,
+    x: f32,
+    y: f32,
+    mods: &Modifiers,
+    related_target: Option<dom::Element>,
+) -> MouseEvent {
+    let mods = mods.state();
+    MouseEvent::new_with_mouse_event_init_dict(
+        name,
+        &MouseEventInit {
+            event: event_init(name),
+            client_x: x as i32,
+            client_y: y as i32,
+            ctrl_key: mods.control_key(),
+            shift_key: mods.shift_key(),
+            alt_key: mods.alt_key(),
+            meta_key: mods.super_key(),
+            related_target,
+            ..Default::default()
+        },
+    )
+}
+
+pub(crate) fn keyboard_event(
+    name: &str,
+    event: &KeyEvent,
+    mods: &Modifiers,
+    is_composing: bool,
+) -> KeyboardEvent {
+    let mods = mods.state();
+    KeyboardEvent::new_with_keyboard_event_init_dict(
+        name,
+        &KeyboardEventInit {
+            event: event_init(name),
+            key: key(&event.logical_key),
+            code: code(&event.physical_key),
+            location: location(event.location),
+            repeat: event.repeat,
+            is_composing,
+            ctrl_key: mods.control_key(),
+            shift_key: mods.shift_key(),
+            alt_key: mods.alt_key(),
+            meta_key: mods.super_key(),
+        },
+    )
+}
+
+pub(crate) fn input_event(
+    name: &str,
+    data: Option<String>,
+    input_type: &str,
+    is_composing: bool,
+) -> InputEvent {
+    InputEvent::new_with_event_init_dict(
+        name,
+        &InputEventInit {
+            event: event_init(name),
+            data,
+            input_type: input_type.to_string(),
+            is_composing,
+        },
+    )
+}
+
+pub(crate) fn composition_event(
+    name: &str,
+    data: String,
+    cursor_range: Option<(usize, usize)>,
+) -> CompositionEvent {
+    CompositionEvent::new_with_event_init_dict(
+        name,
+        &CompositionEventInit {
+            event: event_init(name),
+            data,
+            cursor_range,
+            ..Default::default()
+        },
+    )
+}
+
+/// The event fired at the window for `event`, if it has a DOM counterpart.
+///
+/// A scale factor change resizes the viewport in CSS pixels, so it fires `resize` as well.
+pub(crate) fn window_event(event: &WindowEvent) -> Option<Event> {
+    let event = match event {
+        WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. } => {
+            Event::new_with_event_init_dict("resize", &EventInit::new())
+        }
+        WindowEvent::Focused(focused) => {
+            let name = if *focused { "focus" } else { "blur" };
+            FocusEvent::new_with_focus_event_init_dict(name, &FocusEventInit::new()).into()
+        }
+        WindowEvent::ThemeChanged(theme) => {
+            let mut init = CustomEventInit::new();
+            init.set_detail(match theme {
+                Theme::Light => ColorScheme::Light,
+                Theme::Dark => ColorScheme::Dark,
+            });
+            ThemeChangeEvent::new_with_event_init_dict("themechange", &init).into()
+        }
+        _ => return None,
+    };
+    Some(event)
+}
+
+/// The [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) value.
+///
+/// winit's named keys follow the same W3C specification, so their names can be used as is.
+fn key(key: &Key) -> String {
+    match key {
+        Key::Character(text) => text.to_string(),
+        Key::Named(NamedKey::Space) => " ".to_string(),
+        Key::Named(NamedKey::Super) => "Meta".to_string(),
+        Key::Named(named) => format!("{named:?}"),
+        Key::Dead(_) => "Dead".to_string(),
+        Key::Unidentified(_) => "Unidentified".to_string(),
+    }
+}
+
+/// The [`code`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/code) value.
+fn code(physical_key: &PhysicalKey) -> String {
+    match physical_key {
+        PhysicalKey::Code(KeyCode::SuperLeft) => "MetaLeft".to_string(),
+        PhysicalKey::Code(KeyCode::SuperRight) => "MetaRight".to_string(),
+        PhysicalKey::Code(code) => format!("{code:?}"),
+        PhysicalKey::Unidentified(_) => "Unidentified".to_string(),
+    }
+}
+
+fn location(location: KeyLocation) -> u32 {
+    match location {
+        KeyLocation::Standard => KeyboardEvent::DOM_KEY_LOCATION_STANDARD,
+        KeyLocation::Left => KeyboardEvent::DOM_KEY_LOCATION_LEFT,
+        KeyLocation::Right => KeyboardEvent::DOM_KEY_LOCATION_RIGHT,
+        KeyLocation::Numpad => KeyboardEvent::DOM_KEY_LOCATION_NUMPAD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_some_events_are_cancelable() {
+        for name in ["click", "mousedown", "mouseover", "keydown", "beforeinput"] {
+            assert!(event_init(name).cancelable, "{name}");
+        }
+        for name in [
+            "mouseenter",
+            "mouseleave",
+            "focus",
+            "blur",
+            "input",
+            "resize",
+        ] {
+            assert!(!event_init(name).cancelable, "{name}");
+        }
+
+        let mouseleave = mouse_event("mouseleave", 0.0, 0.0, &Modifiers::default(), None);
+        assert!(!mouseleave.cancelable());
+    }
+}
@@ -1,21 +1,111 @@
-use super::LeptosDocument;
+use super::{event_data, LeptosDocument};
+use blitz_dom::{
+    events::{EventData, RendererEvent},
+    DocumentLike,
+};
 use blitz_shell::{BlitzApplication, BlitzEvent, WindowConfig};
+use blitz_web_api::dom::{self, Event, EventInit, EventTarget};
 use winit::{
     application::ApplicationHandler,
-    event::StartCause,
+    dpi::PhysicalPosition,
+    event::{KeyEvent, Modifiers, StartCause, WindowEvent},
     event_loop::{ActiveEventLoop, EventLoopProxy},
+    keyboard::{Key, NamedKey},
+    window::WindowId,
 };
 use blitz_renderer_vello::BlitzVelloRenderer;
 
 pub struct LeptosNativeApplication {
     inner: BlitzApplication<LeptosDocument, BlitzVelloRenderer>,
+    keyboard_modifiers: Modifiers,
 }
 
 impl LeptosNativeApplication {
     pub fn new(proxy: EventLoopProxy<BlitzEvent>) -> Self {
         Self {
             inner: BlitzApplication::new(proxy.clone()),
+            keyboard_modifiers: Modifiers::default(),
+        }
+    }
+
+    /// blitz only forwards key presses, and only while an element is focused.
+    /// Releases, and presses with nothing focused, are dispatched here instead,
+    /// to the focused element or the root element.
+    ///
+    /// Tab presses are always dispatched here, as blitz would move focus itself
+    /// instead of forwarding them. Returns whether blitz should not see the event.
+    fn handle_key_event(&mut self, window_id: WindowId, event: &KeyEvent) -> bool {
+        let Some(view) = self.inner.windows.get_mut(&window_id) else {
+            return false;
+        };
+
+        let is_tab = event.logical_key == Key::Named(NamedKey::Tab);
+        let focused = view.doc.as_ref().get_focussed_node_id();
+        if event.state.is_pressed() && focused.is_some() && !is_tab {
+            return false;
+        }
+
+        let target = focused.unwrap_or_else(|| view.doc.as_ref().root_element().id);
+        view.doc.handle_event(RendererEvent {
+            target,
+            data: EventData::KeyPress {
+                event: event.clone(),
+                mods: self.keyboard_modifiers,
+            },
+        });
+        view.request_redraw();
+
+        is_tab && event.state.is_pressed()
+    }
+
+    fn handle_pointer_event(
+        &mut self,
+        window_id: WindowId,
+        position: Option<PhysicalPosition<f64>>,
+    ) {
+        let Some(view) = self.inner.windows.get_mut(&window_id) else {
+            return;
+        };
+
+        match position {
+            Some(position) => {
+                let scale = view.doc.as_ref().viewport().scale();
+                let x = position.x as f32 / scale;
+                let y = position.y as f32 / scale;
+                view.doc.handle_pointer_move(x, y, self.keyboard_modifiers);
+            }
+            None => view.doc.handle_pointer_leave(),
+        }
+        view.request_redraw();
+    }
+
+    /// Fires `beforeunload` at the window, and `unload` if no handler cancelled it.
+    ///
+    /// Returns whether the window should be closed.
+    fn handle_close_requested(&mut self, window_id: WindowId) -> bool {
+        let Some(view) = self.inner.windows.get_mut(&window_id) else {
+            return true;
+        };
+
+        let mut init = EventInit::new();
+        init.set_cancelable(true);
+        let before_unload = Event::new_with_event_init_dict("beforeunload", &init);
+        let close = dom::window().dispatch_event(&before_unload);
+        if close {
+            dom::window().dispatch_event(&Event::new("unload"));
         }
+        view.request_redraw();
+
+        close
+    }
+
+    fn handle_window_event(&mut self, window_id: WindowId, event: Event) {
+        let Some(view) = self.inner.windows.get_mut(&window_id) else {
+            return;
+        };
+
+        dom::window().dispatch_event(&event);
+        view.request_redraw();
     }
 
     pub fn add_window(&mut self, window_config: WindowConfig<LeptosDocument, BlitzVelloRenderer>) {
@@ -42,7 +132,33 @@ impl ApplicationHandler<BlitzEvent> for LeptosNativeApplication {
         window_id: winit::window::WindowId,
         event: winit::event::WindowEvent,
     ) {
+        match &event {
+            WindowEvent::ModifiersChanged(mods) => self.keyboard_modifiers = *mods,
+            WindowEvent::KeyboardInput { event, .. } if self.handle_key_event(window_id, event) => {
+                return
+            }
+            WindowEvent::CloseRequested if !self.handle_close_requested(window_id) => return,
+            _ => {}
+        }
+
+        // the window events are fired once blitz has applied them, e.g. resized the viewport
+        let window_event = event_data::window_event(&event);
+
+        // blitz updates its hover state for the pointer first
+        let pointer = match &event {
+            WindowEvent::CursorMoved { position, .. } => Some(Some(*position)),
+            WindowEvent::CursorLeft { .. } => Some(None),
+            _ => None,
+        };
+
         self.inner.window_event(event_loop, window_id, event);
+
+        if let Some(position) = pointer {
+            self.handle_pointer_event(window_id, position);
+        }
+        if let Some(window_event) = window_event {
+            self.handle_window_event(window_id, window_event);
+        }
     }
 
     fn user_event(&mut self, event_loop: &ActiveEventLoop, event: BlitzEvent) {
@@ -1,18 +1,25 @@
+use super::event_data;
 use crate::{
     _leptos::{into_view::IntoView, mount::mount_to},
     _tachys::prelude::Mountable,
-    ev::Event,
+    ev,
 };
 use blitz_dom::{
-    events::EventData, namespace_url, net::Resource, ns, Atom, Document, DocumentLike,
-    ElementNodeData, NodeData, QualName, DEFAULT_CSS,
+    events::{EventData, RendererEvent},
+    namespace_url,
+    net::Resource,
+    ns, Atom, Document, DocumentLike, ElementNodeData, NodeData, QualName, DEFAULT_CSS,
 };
 use blitz_traits::{ColorScheme, Viewport, net::NetProvider};
-use blitz_web_api::dom::{self, BlitzDocument};
+use blitz_web_api::dom::{self, BlitzDocument, EventTarget};
 use futures_util::FutureExt;
 use reactive_graph::owner::Owner;
 use std::sync::Arc;
 use tokio::task::LocalSet;
+use winit::{
+    event::{Ime, KeyEvent, Modifiers},
+    keyboard::{Key, NamedKey},
+};
 
 pub(crate) fn qual_name(local_name: &str, namespace: Option<&str>) -> QualName {
     QualName {
@@ -27,6 +34,14 @@ pub struct LeptosDocument {
     owner: Owner,
     mountable: Box<dyn Mountable>,
     local_set: LocalSet,
+    /// Whether an input method composition is in progress.
+    composing: bool,
+    /// The elements under the pointer, from the innermost one up to the root.
+    hovered: Vec<dom::Element>,
+    /// The last pointer position, in CSS pixels.
+    pointer: (f32, f32),
+    /// The last keyboard modifiers reported alongside a pointer event.
+    mods: Modifiers,
     // inner: Document,
 }
 
@@ -53,46 +68,28 @@ impl DocumentLike for LeptosDocument {
         true
     }
 
-    fn handle_event(&mut self, event: blitz_dom::events::RendererEvent) {
-        // Collect the nodes into a chain by traversing upwards
-        // This is important so the "capture" phase can be implemented
-        let mut next_node_id = Some(event.target);
-        let mut chain = Vec::with_capacity(16);
-
-        // if it's a capturing event, we want to fill in the chain with the parent nodes
-        // until we reach the root - that way we can call the listeners in the correct order
-        // otherwise, we just want to call the listeners on the target
-        //
-        // todo: this is harcoded for "click" events - eventually we actually need to handle proper propagation
-        // if event.name == "click" {
-        while let Some(node_id) = next_node_id {
-            let node = &self.inner().tree()[node_id];
-
-            if let Some(_element) = node.element_data() {
-                chain.push(dom::Element::from(node_id))
-            }
-
-            next_node_id = node.parent;
-        }
+    fn handle_event(&mut self, event: RendererEvent) {
+        let Some(target) = self.target_element(event.target) else {
+            return self.inner_mut().handle_event(event);
+        };
 
-        match event.data {
-            EventData::Click { x, y, mods } => {
-                let doc = BlitzDocument::document();
-                for el in chain.iter().rev() {
-                    if let Some(value) = doc
-                        .get_node(el.node_id())
-                        .unwrap()
-                        .attr(markup5ever::LocalName::from("onclick"))
-                    {
-                        if let Ok(key) = value.parse::<u64>() {
-                            Event::call_mut(key);
-                        }
-                    }
+        let default_allowed = match &event.data {
+            EventData::KeyPress { event, mods } => self.dispatch_key(&target, event, mods),
+            EventData::Ime(_) => return self.handle_ime(&target, event),
+            EventData::Hover => return self.update_hover(Some(target)),
+            data => {
+                // as a mousedown would, a click moves focus before it is dispatched
+                if let EventData::Click { .. } = data {
+                    self.focus_clicked(&target);
                 }
+                target.dispatch_event(&event_data::dom_event(event.name(), data))
             }
-            EventData::KeyPress { event, mods } => todo!(),
-            EventData::Ime(ime) => todo!(),
-            EventData::Hover => todo!(),
+        };
+
+        // blitz's own handling (focus, checkbox toggling, text editing) is the default action,
+        // so it only runs if no handler prevented it
+        if default_allowed {
+            self.inner_mut().handle_event(event);
         }
     }
 }
@@ -107,6 +104,27 @@ impl LeptosDocument {
         F: FnOnce() -> N + 'static,
         N: IntoView + 'static,
     {
+        let root_element = Self::install_document(net_provider);
+
+        let local_set = LocalSet::new();
+        let (owner, mountable) = local_set.block_on(rt, async { mount_to(root_element.into(), f) });
+
+        Self {
+            local_set,
+            owner,
+            mountable,
+            composing: false,
+            hovered: Vec::new(),
+            pointer: (0.0, 0.0),
+            mods: Modifiers::default(),
+        }
+    }
+
+    /// Creates the document views are mounted into and installs it as the current
+    /// [`BlitzDocument`], returning the id of its root element.
+    pub(crate) fn install_document(
+        net_provider: Option<Arc<dyn NetProvider<Data = Resource>>>,
+    ) -> usize {
         let viewport = Viewport::new(0, 0, 1.0, ColorScheme::Light);
         let mut doc = Document::new(viewport);
 
@@ -131,21 +149,289 @@ impl LeptosDocument {
         doc.add_user_agent_stylesheet(DEFAULT_CSS);
 
         let root_element = doc.root_element().id;
-
         BlitzDocument::set_document(doc);
+        root_element
+    }
 
-        let local_set = LocalSet::new();
-        let (owner, mountable) = local_set.block_on(rt, async { mount_to(root_element.into(), f) });
+    fn inner(&self) -> &'static Document {
+        BlitzDocument::document()
+    }
 
-        Self {
-            local_set,
-            owner,
-            mountable,
+    /// Fires `keydown` and `keypress`, or `keyup` for released keys, at `target`.
+    ///
+    /// Returns whether blitz should go on to handle the key, which it only does for presses.
+    fn dispatch_key(&self, target: &dom::Element, event: &KeyEvent, mods: &Modifiers) -> bool {
+        if !event.state.is_pressed() {
+            let keyup = event_data::keyboard_event("keyup", event, mods, self.composing);
+            target.dispatch_event(&keyup);
+            return false;
+        }
+
+        let keydown = event_data::keyboard_event("keydown", event, mods, self.composing);
+        if !target.dispatch_event(&keydown) {
+            return false;
+        }
+
+        // moving focus is the default action of Tab, and replaces blitz's own handling
+        if event.logical_key == Key::Named(NamedKey::Tab) {
+            self.move_focus(mods.state().shift_key());
+            return false;
+        }
+
+        // `keypress` is only fired for keys that produce a character value
+        let state = mods.state();
+        if event.text.is_some() && !state.control_key() && !state.super_key() {
+            let keypress = event_data::keyboard_event("keypress", event, mods, self.composing);
+            return target.dispatch_event(&keypress);
         }
+
+        true
     }
 
-    fn inner(&self) -> &'static Document {
-        BlitzDocument::document()
+    /// Maps input method events to the composition events, and commits to `beforeinput`
+    /// and `input` around blitz inserting the committed text.
+    fn handle_ime(&mut self, target: &dom::Element, event: RendererEvent) {
+        let EventData::Ime(ime) = &event.data else {
+            unreachable!("handle_ime is only called for IME events")
+        };
+
+        match ime.clone() {
+            Ime::Enabled => self.inner_mut().handle_event(event),
+            Ime::Preedit(text, cursor_range) => {
+                // winit clears the preedit text right before a commit, so an empty preedit
+                // does not end the composition by itself
+                if !text.is_empty() && !self.composing {
+                    self.composing = true;
+                    let start =
+                        event_data::composition_event("compositionstart", String::new(), None);
+                    target.dispatch_event(&start);
+                }
+                if self.composing {
+                    let update =
+                        event_data::composition_event("compositionupdate", text, cursor_range);
+                    target.dispatch_event(&update);
+                }
+                self.inner_mut().handle_event(event);
+            }
+            Ime::Commit(text) => {
+                let beforeinput = event_data::input_event(
+                    "beforeinput",
+                    Some(text.clone()),
+                    "insertText",
+                    self.composing,
+                );
+                if target.dispatch_event(&beforeinput) {
+                    self.inner_mut().handle_event(event);
+
+                    // fired once the text is in the input, so handlers can read its new value
+                    let input = event_data::input_event(
+                        "input",
+                        Some(text.clone()),
+                        "insertText",
+                        self.composing,
+                    );
+                    target.dispatch_event(&input);
+                }
+                self.end_composition(target, text);
+            }
+            Ime::Disabled => {
+                self.end_composition(target, String::new());
+                self.inner_mut().handle_event(event);
+            }
+        }
+    }
+
+    /// Called once blitz has updated its hover state for a pointer move to `x`, `y`.
+    pub(crate) fn handle_pointer_move(&mut self, x: f32, y: f32, mods: Modifiers) {
+        self.pointer = (x, y);
+        self.mods = mods;
+
+        let target = self
+            .inner()
+            .get_hover_node_id()
+            .and_then(|node_id| self.target_element(node_id));
+        self.update_hover(target.clone());
+
+        if let Some(target) = target {
+            target.dispatch_event(&self.pointer_event("mousemove", None));
+        }
+    }
+
+    /// Called when the pointer leaves the window.
+    pub(crate) fn handle_pointer_leave(&mut self) {
+        self.update_hover(None);
+    }
+
+    /// Diffs the hovered element chain against the one under `target`, firing `mouseout` and
+    /// `mouseover` at the old and new targets, and `mouseleave` and `mouseenter` at each element
+    /// the pointer has left or entered.
+    fn update_hover(&mut self, target: Option<dom::Element>) {
+        let chain = target
+            .map(|target| self.element_chain(&target))
+            .unwrap_or_default();
+        let previous = std::mem::replace(&mut self.hovered, chain.clone());
+
+        let old_target = previous.first().filter(|el| self.is_connected(el)).cloned();
+        let new_target = chain.first().cloned();
+        if old_target.as_ref().map(|el| el.node_id()) == new_target.as_ref().map(|el| el.node_id())
+        {
+            return;
+        }
+
+        let contains = |chain: &[dom::Element], el: &dom::Element| {
+            chain.iter().any(|e| e.node_id() == el.node_id())
+        };
+
+        if let Some(old_target) = &old_target {
+            let mouseout = self.pointer_event("mouseout", new_target.clone());
+            old_target.dispatch_event(&mouseout);
+        }
+        // innermost first
+        for el in previous.iter().filter(|el| !contains(&chain, el)) {
+            if self.is_connected(el) {
+                let mouseleave = self.pointer_event("mouseleave", new_target.clone());
+                el.dispatch_event(&mouseleave);
+            }
+        }
+
+        if let Some(new_target) = &new_target {
+            let mouseover = self.pointer_event("mouseover", old_target.clone());
+            new_target.dispatch_event(&mouseover);
+        }
+        // outermost first
+        for el in chain.iter().rev().filter(|el| !contains(&previous, el)) {
+            let mouseenter = self.pointer_event("mouseenter", old_target.clone());
+            el.dispatch_event(&mouseenter);
+        }
+    }
+
+    fn pointer_event(&self, name: &str, related_target: Option<dom::Element>) -> ev::Event {
+        let (x, y) = self.pointer;
+        event_data::mouse_event(name, x, y, &self.mods, related_target).into()
+    }
+
+    fn end_composition(&mut self, target: &dom::Element, data: String) {
+        if std::mem::take(&mut self.composing) {
+            let end = event_data::composition_event("compositionend", data, None);
+            target.dispatch_event(&end);
+        }
+    }
+
+    /// Focuses the closest focusable element of the clicked `target`, or blurs the focused
+    /// element if there is none.
+    fn focus_clicked(&self, target: &dom::Element) {
+        let focusable = self
+            .element_chain(target)
+            .into_iter()
+            .find(dom::Element::is_focusable);
+
+        match focusable {
+            Some(el) => el.focus(),
+            None => {
+                if let Some(node_id) = self.inner().get_focussed_node_id() {
+                    dom::Element::from(node_id).blur();
+                }
+            }
+        }
+    }
+
+    /// Moves focus to the next element in sequential focus order, or to the previous one if
+    /// `backwards` is set, wrapping around at either end.
+    fn move_focus(&self, backwards: bool) {
+        let order = self.sequential_focus_order();
+        if order.is_empty() {
+            return;
+        }
+
+        let len = order.len();
+        let current = self
+            .inner()
+            .get_focussed_node_id()
+            .and_then(|node_id| order.iter().position(|el| el.node_id() == node_id));
+        let next = match (current, backwards) {
+            (Some(index), false) => (index + 1) % len,
+            (Some(index), true) => (index + len - 1) % len,
+            (None, false) => 0,
+            (None, true) => len - 1,
+        };
+        order[next].focus();
+    }
+
+    /// The focusable elements of the document that Tab moves through. Elements with a positive
+    /// `tabindex` come first, by ascending `tabindex`, followed by the ones with a `tabindex` of
+    /// zero. Elements with the same `tabindex` keep their tree order.
+    ///
+    /// Elements with a negative `tabindex`, disabled form controls, and elements that aren't
+    /// rendered because they or an ancestor are hidden are left out.
+    fn sequential_focus_order(&self) -> Vec<dom::Element> {
+        let mut order = Vec::new();
+        let mut stack = vec![self.inner().root_element().id];
+        while let Some(node_id) = stack.pop() {
+            let Some(node) = self.inner().get_node(node_id) else {
+                continue;
+            };
+            if node.is_element() {
+                let el = dom::Element::from(node_id);
+                let tab_index = el.tab_index();
+                if tab_index >= 0 && el.is_focusable() {
+                    order.push((tab_index, el));
+                }
+            }
+            stack.extend(node.children.iter().rev().copied());
+        }
+        order.sort_by_key(|(tab_index, _)| match tab_index {
+            0 => i32::MAX,
+            tab_index => *tab_index,
+        });
+        order.into_iter().map(|(_, el)| el).collect()
+    }
+
+    fn inner_mut(&mut self) -> &'static mut Document {
+        BlitzDocument::document_mut()
+    }
+
+    /// `el` and its element ancestors, from `el` up to the root.
+    fn element_chain(&self, el: &dom::Element) -> Vec<dom::Element> {
+        let mut chain = Vec::with_capacity(16);
+        let mut next_node_id = Some(el.node_id());
+        while let Some(node_id) = next_node_id {
+            let Some(node) = self.inner().get_node(node_id) else {
+                break;
+            };
+            if node.is_element() {
+                chain.push(dom::Element::from(node_id));
+            }
+            next_node_id = node.parent;
+        }
+        chain
+    }
+
+    /// Whether `el` is still attached to the document, as hovered elements may have been
+    /// removed since the last pointer move.
+    fn is_connected(&self, el: &dom::Element) -> bool {
+        let root_id = self.inner().root_node().id;
+        let mut next_node_id = Some(el.node_id());
+        while let Some(node_id) = next_node_id {
+            if node_id == root_id {
+                return true;
+            }
+            next_node_id = self.inner().get_node(node_id).and_then(|node| node.parent);
+        }
+        false
+    }
+
+    /// The element an event on `node_id` should be dispatched to, which is the node itself
+    /// or, e.g. for text nodes, its closest element ancestor.
+    fn target_element(&self, node_id: usize) -> Option<dom::Element> {
+        let mut next_node_id = Some(node_id);
+        while let Some(node_id) = next_node_id {
+            let node = self.inner().get_node(node_id)?;
+            if node.is_element() {
+                return Some(dom::Element::from(node_id));
+            }
+            next_node_id = node.parent;
+        }
+        None
     }
 }
 
@@ -154,3 +440,68 @@ impl Drop for LeptosDocument {
         self.mountable.unmount();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use blitz_dom::node::DisplayOuter;
+
+    fn append(parent: &dom::Element, name: &str, attrs: &[(&str, &str)]) -> dom::Element {
+        let el = dom::window().document().create_element_ns(None, name);
+        for (name, value) in attrs {
+            el.set_attribute(name, value);
+        }
+        parent.insert_before(&el, None);
+        el
+    }
+
+    fn focussed() -> Option<usize> {
+        BlitzDocument::document().get_focussed_node_id()
+    }
+
+    #[test]
+    fn tab_follows_tabindex_and_skips_unfocusable_elements() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let doc = LeptosDocument::new(&rt, || (), None);
+        let root = dom::Element::from(doc.inner().root_element().id);
+
+        let button = append(&root, "button", &[]);
+        let second = append(&root, "div", &[("tabindex", "2")]);
+        append(&root, "input", &[("tabindex", "-1")]);
+        let zero = append(&root, "div", &[("tabindex", "0")]);
+        append(&root, "button", &[("tabindex", "1"), ("disabled", "")]);
+        let first = append(&root, "div", &[("tabindex", "1")]);
+        append(&root, "a", &[]);
+        let hidden = append(&root, "div", &[("hidden", "")]);
+        append(&hidden, "button", &[("tabindex", "1")]);
+        let not_displayed = append(&root, "div", &[]);
+        append(&not_displayed, "a", &[("href", "#")]);
+        BlitzDocument::document_mut().nodes[not_displayed.node_id()].display_outer =
+            DisplayOuter::None;
+        let detached = dom::window().document().create_element_ns(None, "button");
+
+        let order = doc
+            .sequential_focus_order()
+            .iter()
+            .map(|el| el.node_id())
+            .collect::<Vec<_>>();
+        let expected = [&first, &second, &button, &zero].map(|el| el.node_id());
+        assert_eq!(order, expected);
+
+        for el in expected.iter().chain(&expected[..1]) {
+            doc.move_focus(false);
+            assert_eq!(focussed(), Some(*el));
+        }
+        doc.move_focus(true);
+        assert_eq!(focussed(), Some(zero.node_id()));
+
+        // the focussed element is no longer in the order once it is detached
+        detached.focus();
+        assert_eq!(focussed(), Some(zero.node_id()));
+        zero.remove();
+        doc.move_focus(false);
+        assert_eq!(focussed(), Some(first.node_id()));
+    }
+}
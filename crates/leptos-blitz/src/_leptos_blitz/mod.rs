@@ -1,3 +1,4 @@
+mod event_data;
 mod leptos_application;
 mod leptos_document;
 
@@ -5,7 +6,7 @@ use crate::_leptos::into_view::IntoView;
 use blitz_net::Provider;
 use blitz_shell::{create_default_event_loop, BlitzEvent, BlitzShellNetCallback, WindowConfig};
 use leptos_application::LeptosNativeApplication;
-use leptos_document::LeptosDocument;
+pub(crate) use leptos_document::LeptosDocument;
 
 // blitz launch_cfg_with_props
 pub fn launch<F, N>(f: F)
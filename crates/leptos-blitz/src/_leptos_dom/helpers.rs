@@ -0,0 +1,69 @@
+//! A variety of DOM utility functions.
+
+use crate::_tachys::html::event::{Event, EventDescriptor};
+use blitz_web_api::dom::{self, EventTarget};
+use std::{any::Any, fmt};
+
+/// Returns the [`Window`](dom::Window).
+pub fn window() -> dom::Window {
+    dom::window()
+}
+
+/// Returns the [`Document`](dom::Document).
+pub fn document() -> dom::Document {
+    window().document().clone()
+}
+
+/// Handle that can be used to remove a listener added with [`window_event_listener`].
+pub struct WindowListenerHandle(Box<dyn FnOnce() + Send + Sync>);
+
+impl fmt::Debug for WindowListenerHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WindowListenerHandle").finish()
+    }
+}
+
+impl WindowListenerHandle {
+    /// Removes the event listener.
+    pub fn remove(self) {
+        (self.0)()
+    }
+}
+
+/// Adds an event listener to the `Window`, typed as a generic `Event`,
+/// returning a cancelable handle.
+pub fn window_event_listener_untyped(
+    event_name: &str,
+    cb: impl Fn(Event) + 'static,
+) -> WindowListenerHandle {
+    let id = window().add_event_listener_with_callback(event_name, cb);
+
+    WindowListenerHandle(Box::new(move || window().remove_event_listener(id)))
+}
+
+/// Creates a window event listener from a typed event, returning a
+/// cancelable handle.
+///
+/// Besides the usual window events, blitz fires `resize` when the window is
+/// resized or its scale factor changes, `focus` and `blur` when it gains or
+/// loses focus, [`themechange`](crate::ev::themechange) when the system color
+/// scheme changes, and `beforeunload` when the user asks to close it.
+/// Calling `prevent_default()` on `beforeunload` keeps the window open.
+///
+/// ```rust,ignore
+/// use leptos_blitz::{ev, prelude::*};
+///
+/// let handle = window_event_listener(ev::resize, |_| {
+///     // the viewport has already been resized at this point
+/// });
+/// on_cleanup(move || handle.remove());
+/// ```
+pub fn window_event_listener<E: EventDescriptor + Any>(
+    event: E,
+    cb: impl Fn(E::EventType) + 'static,
+) -> WindowListenerHandle
+where
+    E::EventType: From<Event>,
+{
+    window_event_listener_untyped(&event.name(), move |e| cb(E::EventType::from(e)))
+}
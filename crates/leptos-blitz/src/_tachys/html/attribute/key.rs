@@ -32,6 +32,8 @@ macro_rules! attributes {
     }
 }
 
+pub(crate) use attributes;
+
 attributes! {
     // HTML
     /// The `abbr` attribute specifies an abbreviated form of the element's content.
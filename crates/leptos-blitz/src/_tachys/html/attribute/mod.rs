@@ -1,3 +1,7 @@
+/// A type-erased attribute.
+pub mod any_attribute;
+/// Types for custom attributes.
+pub mod custom;
 mod key;
 mod value;
 
@@ -312,7 +316,9 @@ macro_rules! impl_attr_for_tuples {
 	};
 }
 
-macro_rules! impl_attr_for_tuples_truncate_additional {
+/// Like `impl_attr_for_tuples`, for the largest tuple: adding another attribute nests it, so
+/// that an element can have any number of attributes.
+macro_rules! impl_attr_for_tuples_nest_additional {
 	($first:ident, $($ty:ident),* $(,)?) => {
 		impl<$first, $($ty),*> Attribute for ($first, $($ty,)*)
 		where
@@ -408,14 +414,14 @@ macro_rules! impl_attr_for_tuples_truncate_additional {
 			$($ty: Attribute),*,
 
         {
-            type Output<NewAttr: Attribute> = ($first, $($ty,)*);
+            // the full tuple becomes the first item of a new one, which can grow in turn
+            type Output<NewAttr: Attribute> = (Self, NewAttr);
 
             fn add_any_attr<NewAttr: Attribute>(
                 self,
-                _new_attr: NewAttr,
+                new_attr: NewAttr,
             ) -> Self::Output<NewAttr> {
-                todo!("adding more than 26 attributes is not supported");
-                //($first, $($ty,)*)
+                (self, new_attr)
             }
 		}
 	};
@@ -510,6 +516,6 @@ impl_attr_for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T
 impl_attr_for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W);
 impl_attr_for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X);
 impl_attr_for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y);
-impl_attr_for_tuples_truncate_additional!(
+impl_attr_for_tuples_nest_additional!(
     A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z
 );
@@ -0,0 +1,310 @@
+use super::{
+    attribute::{Attribute, NextAttribute},
+    element::HtmlElement,
+};
+use crate::_tachys::renderer::types;
+use reactive_graph::owner::Owner;
+use send_wrapper::SendWrapper;
+use std::{marker::PhantomData, sync::Arc};
+
+impl<E, At, Ch> HtmlElement<E, At, Ch>
+where
+    At: Attribute,
+{
+    /// Adds a directive to the element, which runs some custom logic when the element is created.
+    pub fn directive<T, D, P>(
+        self,
+        handler: D,
+        param: P,
+    ) -> HtmlElement<E, <At as NextAttribute>::Output<Directive<T, D, P>>, Ch>
+    where
+        D: IntoDirective<T, P>,
+        P: Clone + 'static,
+        T: 'static,
+        At: NextAttribute,
+        <At as NextAttribute>::Output<Directive<T, D, P>>: Attribute,
+    {
+        let HtmlElement {
+            tag,
+            children,
+            attributes,
+            #[cfg(debug_assertions)]
+            defined_at,
+        } = self;
+        HtmlElement {
+            tag,
+            children,
+            attributes: attributes.add_any_attr(directive(handler, param)),
+            #[cfg(debug_assertions)]
+            defined_at,
+        }
+    }
+}
+
+/// Adds a directive to the element, which runs some custom logic when the element is created.
+#[inline(always)]
+pub fn directive<T, P, D>(handler: D, param: P) -> Directive<T, D, P>
+where
+    D: IntoDirective<T, P>,
+{
+    Directive(Some(SendWrapper::new(DirectiveInner {
+        handler,
+        param,
+        t: PhantomData,
+    })))
+}
+
+/// Custom logic that runs when the element is created.
+#[derive(Debug)]
+pub struct Directive<T, D, P>(Option<SendWrapper<DirectiveInner<T, D, P>>>);
+
+impl<T, D, P> Clone for Directive<T, D, P>
+where
+    P: Clone + 'static,
+    D: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[derive(Debug)]
+struct DirectiveInner<T, D, P> {
+    handler: D,
+    param: P,
+    t: PhantomData<T>,
+}
+
+impl<T, D, P> Clone for DirectiveInner<T, D, P>
+where
+    P: Clone + 'static,
+    D: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+            param: self.param.clone(),
+            t: PhantomData,
+        }
+    }
+}
+
+impl<T, D, P> DirectiveInner<T, D, P>
+where
+    D: IntoDirective<T, P>,
+{
+    /// Runs the handler under a new reactive owner, so that anything it registers with
+    /// `on_cleanup`, and any effects it creates, are torn down when the owner is cleaned up.
+    fn run(self, el: &types::Element) -> Owner {
+        let owner = Owner::new();
+        owner.with(|| self.handler.run(el.clone(), self.param));
+        owner
+    }
+}
+
+/// Retained state for a [`Directive`]. The directive's owner is cleaned up when the element is
+/// unmounted and this state is dropped, or before the directive runs again on rebuild.
+pub struct DirectiveState {
+    el: types::Element,
+    owner: Owner,
+}
+
+impl Drop for DirectiveState {
+    fn drop(&mut self) {
+        self.owner.cleanup();
+    }
+}
+
+impl<T, D, P> Attribute for Directive<T, D, P>
+where
+    D: IntoDirective<T, P>,
+    P: Clone + 'static,
+    T: 'static,
+{
+    const MIN_LENGTH: usize = 0;
+
+    type AsyncOutput = Self;
+    type State = DirectiveState;
+    type Cloneable = Directive<T, D::Cloneable, P>;
+    type CloneableOwned = Directive<T, D::Cloneable, P>;
+
+    #[inline(always)]
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(
+        self,
+        _buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        _inner_html: &mut String,
+    ) {
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+        self.build(el)
+    }
+
+    fn build(self, el: &types::Element) -> Self::State {
+        let inner = self.0.expect("directive removed early").take();
+        DirectiveState {
+            el: el.clone(),
+            owner: inner.run(el),
+        }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let inner = self.0.expect("directive removed early").take();
+        state.owner.cleanup();
+        state.owner = inner.run(&state.el);
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self.into_cloneable_owned()
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        let inner = self.0.map(|inner| {
+            let DirectiveInner { handler, param, t } = inner.take();
+            SendWrapper::new(DirectiveInner {
+                handler: handler.into_cloneable(),
+                param,
+                t,
+            })
+        });
+        Directive(inner)
+    }
+
+    fn dry_resolve(&mut self) {
+        // the directive is never run when rendering to HTML, and the `SendWrapper` should not be
+        // dropped from another thread
+        self.0.take();
+    }
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+}
+
+impl<T, D, P> NextAttribute for Directive<T, D, P>
+where
+    D: IntoDirective<T, P>,
+    P: Clone + 'static,
+    T: 'static,
+{
+    type Output<NewAttr: Attribute> = (Self, NewAttr);
+
+    fn add_any_attr<NewAttr: Attribute>(self, new_attr: NewAttr) -> Self::Output<NewAttr> {
+        (self, new_attr)
+    }
+}
+
+/// Trait for a directive handler function.
+/// This is used so it's possible to use functions with one or two
+/// parameters as directive handlers.
+///
+/// You can use directives like the following.
+///
+/// ```ignore
+/// # use leptos_blitz::{prelude::*, tachys::renderer::types::Element};
+///
+/// // This doesn't take an attribute value
+/// fn my_directive(el: Element) {
+///     // do sth
+/// }
+///
+/// // This requires an attribute value
+/// fn another_directive(el: Element, params: i32) {
+///     // do sth
+///     on_cleanup(move || {
+///         // undo sth when the element is unmounted
+///     });
+/// }
+///
+/// #[component]
+/// pub fn MyComponent() -> impl IntoView {
+///     view! {
+///         // no attribute value
+///         <div use:my_directive></div>
+///
+///         // with an attribute value
+///         <div use:another_directive=8></div>
+///     }
+/// }
+/// ```
+///
+/// A directive can be a function with one or two parameters.
+/// The first is the element the directive is added to and the optional
+/// second is the parameter that is provided in the attribute.
+///
+/// The directive runs under its own reactive owner, so cleanup functions registered with
+/// `on_cleanup` run, and effects it creates are disposed, when the element is unmounted.
+pub trait IntoDirective<T: ?Sized, P> {
+    /// An equivalent to this directive that is cloneable and owned.
+    type Cloneable: IntoDirective<T, P> + Clone + 'static;
+
+    /// Calls the handler function
+    fn run(&self, el: types::Element, param: P);
+
+    /// Converts this into a cloneable type.
+    fn into_cloneable(self) -> Self::Cloneable;
+}
+
+impl<F> IntoDirective<(types::Element,), ()> for F
+where
+    F: Fn(types::Element) + 'static,
+{
+    type Cloneable = Arc<dyn Fn(types::Element)>;
+
+    fn run(&self, el: types::Element, _: ()) {
+        self(el)
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        Arc::new(self)
+    }
+}
+
+impl IntoDirective<(types::Element,), ()> for Arc<dyn Fn(types::Element)> {
+    type Cloneable = Arc<dyn Fn(types::Element)>;
+
+    fn run(&self, el: types::Element, _: ()) {
+        self(el)
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+}
+
+impl<F, P> IntoDirective<(types::Element, P), P> for F
+where
+    F: Fn(types::Element, P) + 'static,
+    P: 'static,
+{
+    type Cloneable = Arc<dyn Fn(types::Element, P)>;
+
+    fn run(&self, el: types::Element, param: P) {
+        self(el, param);
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        Arc::new(self)
+    }
+}
+
+impl<P> IntoDirective<(types::Element, P), P> for Arc<dyn Fn(types::Element, P)>
+where
+    P: 'static,
+{
+    type Cloneable = Arc<dyn Fn(types::Element, P)>;
+
+    fn run(&self, el: types::Element, param: P) {
+        self(el, param)
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+}
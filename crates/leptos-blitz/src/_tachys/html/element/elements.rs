@@ -1,11 +1,10 @@
 use crate::_tachys::{
     html::{
-        attribute::{Attr, Attribute, AttributeValue},
+        attribute::{Attr, Attribute, AttributeValue, NextAttribute},
         element::{ElementType, ElementWithChildren, HtmlElement},
     },
     view::Render,
 };
-use next_tuple::NextTuple;
 use std::fmt::Debug;
 
 macro_rules! html_element_inner {
@@ -48,13 +47,13 @@ macro_rules! html_element_inner {
                     #[doc = concat!("The [`", stringify!($attr), "`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/", stringify!($tag), "#", stringify!($attr) ,") attribute on `<", stringify!($tag), ">`.")]
                     pub fn $attr<V>(self, value: V) -> HtmlElement <
                         $struct_name,
-                        <At as NextTuple>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>,
+                        <At as NextAttribute>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>,
                         Ch
                     >
                     where
                         V: AttributeValue,
-                        At: NextTuple,
-                        <At as NextTuple>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>: Attribute,
+                        At: NextAttribute,
+                        <At as NextAttribute>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>: Attribute,
                     {
                         let HtmlElement {
                             #[cfg(debug_assertions)]
@@ -68,7 +67,7 @@ macro_rules! html_element_inner {
                             defined_at,
                             tag,
                             children,
-                            attributes: attributes.next_tuple($crate::_tachys::html::attribute::$attr(value)),
+                            attributes: attributes.add_any_attr($crate::_tachys::html::attribute::$attr(value)),
                         }
                     }
                 )*
@@ -153,13 +152,13 @@ macro_rules! html_self_closing_elements {
                         #[doc = concat!("The [`", stringify!($attr), "`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/", stringify!($tag), "#", stringify!($attr) ,") attribute on `<", stringify!($tag), ">`.")]
                         pub fn $attr<V>(self, value: V) -> HtmlElement<
                             [<$tag:camel>],
-                            <At as NextTuple>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>,
+                            <At as NextAttribute>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>,
                             (),
                         >
                         where
                             V: AttributeValue,
-                            At: NextTuple,
-                            <At as NextTuple>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>: Attribute,
+                            At: NextAttribute,
+                            <At as NextAttribute>::Output<Attr<$crate::_tachys::html::attribute::[<$attr:camel>], V>>: Attribute,
                         {
                             let HtmlElement {
                                  #[cfg(debug_assertions)]
@@ -173,7 +172,7 @@ macro_rules! html_self_closing_elements {
                                 defined_at,
                                 tag,
                                 children,
-                                attributes: attributes.next_tuple($crate::_tachys::html::attribute::$attr(value)),
+                                attributes: attributes.add_any_attr($crate::_tachys::html::attribute::$attr(value)),
                             }
                         }
                     )*
@@ -1,9 +1,14 @@
 mod elements;
+mod inner_html;
 
 pub use elements::*;
+pub use inner_html::*;
 
 use crate::_tachys::{
-    html::attribute::{escape_attr, Attribute, NextAttribute},
+    html::{
+        attribute::{escape_attr, Attribute, NextAttribute},
+        node_ref::{mount_node_refs, unmount_node_refs},
+    },
     renderer::{types, CastFrom, Rndr},
     ssr::StreamBuilder,
     view::{add_attr::AddAnyAttr, IntoRender, Mountable, Position, Render, RenderHtml},
@@ -125,7 +130,9 @@ pub trait ElementType: Send {
     /// The element's tag.
     fn tag(&self) -> &str;
 }
-pub(crate) trait ElementWithChildren {}
+
+/// Marks an element that can have children, as opposed to a void element like `<input>`.
+pub trait ElementWithChildren {}
 
 impl<E, At, Ch> Render for HtmlElement<E, At, Ch>
 where
@@ -346,11 +353,13 @@ impl<At, Ch> Deref for ElementState<At, Ch> {
 
 impl<At, Ch> Mountable for ElementState<At, Ch> {
     fn unmount(&mut self) {
+        unmount_node_refs(&self.el);
         Rndr::remove(&self.el);
     }
 
     fn mount(&mut self, parent: &types::Element, marker: Option<&types::Node>) {
         Rndr::insert_node(parent, &self.el, marker);
+        mount_node_refs(&self.el);
     }
 
     fn insert_before_this(&self, child: &mut dyn Mountable) -> bool {
@@ -2,11 +2,11 @@ use super::{
     attribute::{Attribute, NextAttribute},
     element::HtmlElement,
 };
-use crate::_tachys::renderer::{types, Rndr};
-use next_tuple::NextTuple;
+use crate::_tachys::renderer::types;
+use blitz_traits::ColorScheme;
+use blitz_web_api::dom::{AddEventListenerOptions, EventTarget};
 use send_wrapper::SendWrapper;
-use slotmap::{DefaultKey, Key, KeyData, SlotMap};
-use std::{borrow::Cow, cell::RefCell, fmt::Debug, rc::Rc};
+use std::{borrow::Cow, cell::RefCell, fmt::Debug, marker::PhantomData, rc::Rc};
 
 impl<E, At, Ch> HtmlElement<E, At, Ch>
 where
@@ -16,14 +16,14 @@ where
         self,
         event: EV,
         cb: F,
-    ) -> HtmlElement<E, <At as NextTuple>::Output<On<EV, F>>, Ch>
+    ) -> HtmlElement<E, <At as NextAttribute>::Output<On<EV, F>>, Ch>
     where
         EV: EventDescriptor + Send + 'static,
         EV::EventType: 'static,
         EV::EventType: From<Event>,
         F: FnMut(EV::EventType) + 'static,
-        At: NextTuple,
-        <At as NextTuple>::Output<On<EV, F>>: Attribute,
+        At: NextAttribute,
+        <At as NextAttribute>::Output<On<EV, F>>: Attribute,
     {
         let HtmlElement {
             tag,
@@ -35,17 +35,13 @@ where
         HtmlElement {
             tag,
             children,
-            attributes: attributes.next_tuple(on(event, cb)),
+            attributes: attributes.add_any_attr(on(event, cb)),
             #[cfg(debug_assertions)]
             defined_at,
         }
     }
 }
 
-thread_local! {
-    static EVENTS: RefCell<SlotMap<DefaultKey, Box<dyn FnMut(Event)>>> = Default::default();
-}
-
 pub type SharedEventCallback<E> = Rc<RefCell<dyn FnMut(E)>>;
 
 pub trait EventCallback<E>: 'static {
@@ -78,7 +74,8 @@ where
     }
 }
 
-fn on<E, F>(event: E, cb: F) -> On<E, F>
+/// Creates an [`Attribute`] that will add an event listener to an element.
+pub fn on<E, F>(event: E, cb: F) -> On<E, F>
 where
     F: FnMut(E::EventType) + 'static,
     E: EventDescriptor + Send + 'static,
@@ -132,31 +129,56 @@ where
     pub fn attach(self, el: &types::Element) -> RemoveEventHandler<types::Element> {
         fn attach_inner(
             el: &types::Element,
-            cb: Box<dyn FnMut(Event)>,
-            html_name: Cow<'static, str>,
-            _delegation_key: Option<Cow<'static, str>>,
+            cb: SharedEventCallback<Event>,
+            name: &str,
+            capture: bool,
+            options: Option<&AddEventListenerOptions>,
         ) -> RemoveEventHandler<types::Element> {
-            let key = Event::insert(cb);
-            Rndr::set_attribute(el, &html_name, &key.to_string());
-
-            RemoveEventHandler::new(move |_| {
-                Event::remove(key);
+            let mut options = options.cloned().unwrap_or_default();
+            options.capture |= capture;
+            let id = el.add_event_listener_with_callback_and_add_event_listener_options(
+                name,
+                move |ev| cb.borrow_mut()(ev),
+                &options,
+            );
+
+            RemoveEventHandler::new(move |el: &types::Element| {
+                el.remove_event_listener(id);
             })
         }
 
         let mut cb = self.cb.take();
 
-        let cb = Box::new(move |ev: Event| {
+        let cb = Rc::new(RefCell::new(move |ev: Event| {
             let ev = E::EventType::from(ev);
             cb.invoke(ev);
-        }) as Box<dyn FnMut(Event)>;
+        })) as SharedEventCallback<Event>;
+
+        attach_inner(el, cb, &self.event.name(), E::CAPTURE, self.event.options())
+    }
+}
+
+/// Retained state for an [`On`] attribute. The listener is removed again before it is
+/// re-attached on rebuild, and when the element's view state is dropped.
+///
+/// Listeners are not tied to the element being attached to the document, so an element that
+/// is unmounted and mounted again (for example by a keyed list or a `Suspense`) keeps them.
+pub struct OnState {
+    el: types::Element,
+    cleanup: Option<RemoveEventHandler<types::Element>>,
+}
+
+impl OnState {
+    fn remove(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            (cleanup.into_inner())(&self.el);
+        }
+    }
+}
 
-        attach_inner(
-            el,
-            cb,
-            self.event.html_name(),
-            (E::BUBBLES).then(|| self.event.event_delegation_key()),
-        )
+impl Drop for OnState {
+    fn drop(&mut self) {
+        self.remove();
     }
 }
 
@@ -178,8 +200,7 @@ where
 {
     const MIN_LENGTH: usize = 0;
     type AsyncOutput = Self;
-    // a function that can be called once to remove the event listener
-    type State = (types::Element, Option<RemoveEventHandler<types::Element>>);
+    type State = OnState;
     type Cloneable = On<E, SharedEventCallback<E::EventType>>;
     type CloneableOwned = On<E, SharedEventCallback<E::EventType>>;
 
@@ -200,23 +221,22 @@ where
 
     #[inline(always)]
     fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
-        let cleanup = self.attach(el);
-        (el.clone(), Some(cleanup))
+        self.build(el)
     }
 
     #[inline(always)]
     fn build(self, el: &types::Element) -> Self::State {
         let cleanup = self.attach(el);
-        (el.clone(), Some(cleanup))
+        OnState {
+            el: el.clone(),
+            cleanup: Some(cleanup),
+        }
     }
 
     #[inline(always)]
     fn rebuild(self, state: &mut Self::State) {
-        let (el, prev_cleanup) = state;
-        if let Some(prev) = prev_cleanup.take() {
-            (prev.into_inner())(el);
-        }
-        *prev_cleanup = Some(self.attach(el));
+        state.remove();
+        state.cleanup = Some(self.attach(&state.el));
     }
 
     fn into_cloneable(self) -> Self::Cloneable {
@@ -267,51 +287,154 @@ pub trait EventDescriptor: Clone {
     /// otherwise, event listeners will be directly attached to the element.
     const BUBBLES: bool;
 
+    /// Indicates if this event should be handled during the capture phase.
+    const CAPTURE: bool = false;
+
     /// The name of the event, such as `click` or `mouseover`.
     fn name(&self) -> Cow<'static, str>;
 
-    /// e.g. `onclick` or `onmouseover`
+    /// e.g. `onclick` or `onmouseover`, or `onclickcapture` for capturing handlers
     fn html_name(&self) -> Cow<'static, str>;
 
     /// The key used for event delegation.
     fn event_delegation_key(&self) -> Cow<'static, str>;
 
-    // /// Return the options for this type. This is only used when you create a [`Custom`] event
-    // /// handler.
-    // #[inline(always)]
-    // fn options(&self) -> &Option<web_sys::AddEventListenerOptions> {
-    //     &None
-    // }
+    /// Return the options for this type. This is only used when you create a [`Custom`] event
+    /// handler.
+    #[inline(always)]
+    fn options(&self) -> Option<&AddEventListenerOptions> {
+        None
+    }
+}
+
+/// A wrapper that tells the framework to handle an event during the capture phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capture<E> {
+    inner: E,
+}
+
+/// Wraps an event to indicate that it should be handled during the capture phase.
+pub fn capture<E>(event: E) -> Capture<E> {
+    Capture { inner: event }
+}
+
+impl<E: EventDescriptor> EventDescriptor for Capture<E> {
+    type EventType = E::EventType;
+
+    const CAPTURE: bool = true;
+    const BUBBLES: bool = E::BUBBLES;
+
+    fn name(&self) -> Cow<'static, str> {
+        self.inner.name()
+    }
+
+    fn html_name(&self) -> Cow<'static, str> {
+        format!("{}capture", self.inner.html_name()).into()
+    }
+
+    fn event_delegation_key(&self) -> Cow<'static, str> {
+        self.inner.event_delegation_key()
+    }
+
+    #[inline(always)]
+    fn options(&self) -> Option<&AddEventListenerOptions> {
+        self.inner.options()
+    }
+}
+
+/// A custom event.
+#[derive(Debug)]
+pub struct Custom<E = Event> {
+    name: Cow<'static, str>,
+    options: Option<AddEventListenerOptions>,
+    _event_type: PhantomData<fn() -> E>,
 }
 
-pub struct Event;
+impl<E> Clone for Custom<E> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            options: self.options.clone(),
+            _event_type: PhantomData,
+        }
+    }
+}
 
-impl Event {
-    pub(crate) fn insert(cb: Box<dyn FnMut(Event)>) -> u64 {
-        let key = EVENTS.with_borrow_mut(|events| events.insert(cb));
+impl<E> EventDescriptor for Custom<E> {
+    type EventType = E;
 
-        key.data().as_ffi()
+    fn name(&self) -> Cow<'static, str> {
+        self.name.clone()
     }
 
-    pub(crate) fn remove(key: u64) {
-        let key = KeyData::from_ffi(key).into();
+    fn html_name(&self) -> Cow<'static, str> {
+        format!("on{}", self.name).into()
+    }
 
-        EVENTS.with_borrow_mut(|events| {
-            events.remove(key);
-        });
+    fn event_delegation_key(&self) -> Cow<'static, str> {
+        format!("$$${}", self.name).into()
     }
 
-    pub(crate) fn call_mut(key: u64) {
-        let key = KeyData::from_ffi(key).into();
+    const BUBBLES: bool = false;
 
-        EVENTS.with_borrow_mut(|events| {
-            if let Some(event) = events.get_mut(key) {
-                event(Event {});
-            }
-        });
+    #[inline(always)]
+    fn options(&self) -> Option<&AddEventListenerOptions> {
+        self.options.as_ref()
     }
 }
 
+impl<E> Custom<E> {
+    /// Creates a custom event type that can be used within
+    /// [`HtmlElement::on`], for events which are not covered in the
+    /// [`ev`](crate::_tachys::html::event) module.
+    ///
+    /// Handlers receive the event as `E`, e.g. a [`CustomEvent<T>`] whose `detail()` is the
+    /// payload it was dispatched with:
+    ///
+    /// ```rust,ignore
+    /// view! {
+    ///     <div on:color-picked=|ev: ev::CustomEvent<Color>| set_color(ev.detail().cloned())>
+    ///         ...
+    ///     </div>
+    /// }
+    ///
+    /// // somewhere inside the <div>
+    /// let mut init = ev::CustomEventInit::new();
+    /// init.event.set_bubbles(true);
+    /// init.set_detail(Color::Red);
+    /// el.dispatch_event(&ev::CustomEvent::new_with_event_init_dict("color-picked", &init));
+    /// ```
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            options: None,
+            _event_type: PhantomData,
+        }
+    }
+
+    /// Modify the [`AddEventListenerOptions`] used for this event listener.
+    ///
+    /// ```rust,ignore
+    /// let mut once = ev::Custom::new("close");
+    /// once.options_mut().set_once(true);
+    /// ```
+    pub fn options_mut(&mut self) -> &mut AddEventListenerOptions {
+        self.options
+            .get_or_insert_with(AddEventListenerOptions::new)
+    }
+}
+
+/// Whether the event with the given name bubbles, as described by its [`EventDescriptor`].
+///
+/// Unknown (e.g. custom) events are assumed to bubble.
+pub(crate) fn bubbles(name: &str) -> bool {
+    EVENT_BUBBLES
+        .iter()
+        .find(|(event, _)| *event == name)
+        .map(|(_, bubbles)| *bubbles)
+        .unwrap_or(true)
+}
+
 macro_rules! generate_event_types {
   {$(
     $( #[$does_not_bubble:ident] )?
@@ -319,13 +442,13 @@ macro_rules! generate_event_types {
   ),* $(,)?} => {
     ::paste::paste! {
       $(
-        #[doc = "The `" [< $($event)+ >] "` event, which receives [" $web_event "](web_sys::" $web_event ") as its argument."]
+        #[doc = "The `" [< $($event)+ >] "` event, which receives [`" $web_event "`] as its argument."]
         #[derive(Copy, Clone, Debug)]
         #[allow(non_camel_case_types)]
         pub struct [<$( $event )+ >];
 
         impl EventDescriptor for [< $($event)+ >] {
-          type EventType = Event;
+          type EventType = $web_event;
 
           #[inline(always)]
           fn name(&self) -> Cow<'static, str> {
@@ -345,6 +468,13 @@ macro_rules! generate_event_types {
           const BUBBLES: bool = true $(&& generate_event_types!($does_not_bubble))?;
         }
       )*
+
+      /// The name of every known event, and whether it bubbles.
+      const EVENT_BUBBLES: &[(&str, bool)] = &[
+        $(
+          (stringify!([< $($event)+ >]), [< $($event)+ >]::BUBBLES),
+        )*
+      ];
     }
   };
 
@@ -435,9 +565,7 @@ generate_event_types! {
   error: ErrorEvent,
   #[does_not_bubble]
   focus: FocusEvent,
-  #[does_not_bubble]
   focus in: FocusEvent,
-  #[does_not_bubble]
   focus out: FocusEvent,
   form data: Event, // web_sys does not include `FormDataEvent`
   #[does_not_bubble]
@@ -558,17 +686,82 @@ generate_event_types! {
   #[does_not_bubble]
   ready state change: Event,
   visibility change: Event,
+
+  // =========================================================
+  // blitz
+  // =========================================================
+  #[does_not_bubble]
+  theme change: ThemeChangeEvent,
 }
 
-// Export `web_sys` event types
-// use super::{attribute::NextAttribute, element::HasElementType};
-// #[doc(no_inline)]
-// pub use web_sys::{
-//     AnimationEvent, BeforeUnloadEvent, CompositionEvent, CustomEvent,
-//     DeviceMotionEvent, DeviceOrientationEvent, DragEvent, ErrorEvent, Event,
-//     FocusEvent, GamepadEvent, HashChangeEvent, InputEvent, KeyboardEvent,
-//     MessageEvent, MouseEvent, PageTransitionEvent, PointerEvent, PopStateEvent,
-//     ProgressEvent, PromiseRejectionEvent, SecurityPolicyViolationEvent,
-//     StorageEvent, SubmitEvent, TouchEvent, TransitionEvent, UiEvent,
-//     WheelEvent,
-// };
+// Export `blitz_web_api` event types
+#[doc(no_inline)]
+pub use blitz_web_api::dom::{
+    CompositionEvent, CompositionEventInit, CustomEvent, CustomEventInit, Event, EventInit,
+    FocusEvent, FocusEventInit, InputEvent, InputEventInit, KeyboardEvent, KeyboardEventInit,
+    MouseEvent, MouseEventInit, PointerEvent, PointerEventInit, WheelEvent, WheelEventInit,
+};
+
+/// Fired at the window when the system switches between light and dark mode, with the new
+/// [`ColorScheme`] as its `detail()`.
+pub type ThemeChangeEvent = CustomEvent<ColorScheme>;
+
+// Events whose interfaces blitz_web_api does not implement yet are received as a plain `Event`
+type AnimationEvent = Event;
+type BeforeUnloadEvent = Event;
+type DeviceMotionEvent = Event;
+type DeviceOrientationEvent = Event;
+type DragEvent = Event;
+type ErrorEvent = Event;
+type GamepadEvent = Event;
+type HashChangeEvent = Event;
+type MessageEvent = Event;
+type PageTransitionEvent = Event;
+type PopStateEvent = Event;
+type ProgressEvent = Event;
+type PromiseRejectionEvent = Event;
+type SecurityPolicyViolationEvent = Event;
+type StorageEvent = Event;
+type SubmitEvent = Event;
+type TouchEvent = Event;
+type TransitionEvent = Event;
+type UiEvent = Event;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{_leptos_blitz::LeptosDocument, prelude::*};
+    use blitz_web_api::dom::{Element, MouseEvent, MouseEventInit};
+
+    #[test]
+    fn focusin_and_focusout_bubble_unlike_focus_and_blur() {
+        assert!(bubbles("focusin"));
+        assert!(bubbles("focusout"));
+        assert!(!bubbles("focus"));
+        assert!(!bubbles("blur"));
+        assert!(bubbles("custom"));
+    }
+
+    #[test]
+    fn event_modifiers_choose_the_phase() {
+        let root = Element::from(LeptosDocument::install_document(None));
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (capture_log, bubble_log, button_log) = (log.clone(), log.clone(), log.clone());
+        let mut state = view! {
+            <div
+                on:click:capture=move |_| capture_log.borrow_mut().push("capture")
+                on:click=move |_| bubble_log.borrow_mut().push("bubble")
+            >
+                <button on:click:undelegated=move |_| button_log.borrow_mut().push("target")></button>
+            </div>
+        }
+        .build();
+        state.mount(&root, None);
+
+        let button = Element::from(state.first_child().unwrap().node_id());
+        let mut init = MouseEventInit::new();
+        init.event.set_bubbles(true);
+        button.dispatch_event(&MouseEvent::new_with_mouse_event_init_dict("click", &init).into());
+        assert_eq!(*log.borrow(), ["capture", "target", "bubble"]);
+    }
+}
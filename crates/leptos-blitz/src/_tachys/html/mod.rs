@@ -11,10 +11,18 @@ use std::borrow::Cow;
 
 /// Types for HTML attributes.
 pub mod attribute;
+/// Types for manipulating the `class` attribute and `classList`.
+pub mod class;
+/// Types for `use:` directives.
+pub mod directive;
 /// Types for HTML elements.
 pub mod element;
 /// Types for DOM events.
 pub mod event;
+/// Types for holding a reference to an element once it is mounted.
+pub mod node_ref;
+/// Types for setting DOM properties.
+pub mod property;
 /// Types for the `style` attribute and individual style manipulation.
 pub mod style;
 
@@ -0,0 +1,399 @@
+use super::{
+    attribute::{Attribute, NextAttribute},
+    element::{ElementType, HtmlElement},
+};
+use crate::_tachys::renderer::types;
+use blitz_web_api::dom::BlitzDocument;
+use slotmap::{DefaultKey, SlotMap};
+use std::{cell::RefCell, collections::HashMap, marker::PhantomData, rc::Rc};
+
+impl<E, At, Ch> HtmlElement<E, At, Ch>
+where
+    E: ElementType + 'static,
+    At: Attribute,
+{
+    /// Binds this HTML element to a [`NodeRefContainer`].
+    pub fn node_ref<C>(
+        self,
+        container: C,
+    ) -> HtmlElement<E, <At as NextAttribute>::Output<NodeRefAttr<E, C>>, Ch>
+    where
+        C: NodeRefContainer<E>,
+        At: NextAttribute,
+        <At as NextAttribute>::Output<NodeRefAttr<E, C>>: Attribute,
+    {
+        let HtmlElement {
+            tag,
+            children,
+            attributes,
+            #[cfg(debug_assertions)]
+            defined_at,
+        } = self;
+        HtmlElement {
+            tag,
+            children,
+            attributes: attributes.add_any_attr(node_ref(container)),
+            #[cfg(debug_assertions)]
+            defined_at,
+        }
+    }
+}
+
+/// Describes a container that can be used to hold a reference to an HTML element.
+pub trait NodeRefContainer<E>: Send + Clone + 'static
+where
+    E: ElementType,
+{
+    /// Fills the container with the element.
+    fn load(self, el: &types::Element);
+
+    /// Empties the container, if it still holds this element.
+    fn unload(self, el: &types::Element);
+}
+
+/// An [`Attribute`] that will fill a [`NodeRefContainer`] with an HTML element.
+#[derive(Debug)]
+pub struct NodeRefAttr<E, C> {
+    container: C,
+    ty: PhantomData<E>,
+}
+
+impl<E, C> Clone for NodeRefAttr<E, C>
+where
+    C: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            container: self.container.clone(),
+            ty: PhantomData,
+        }
+    }
+}
+
+/// Creates an attribute that will fill a [`NodeRefContainer`] with the element it is applied to.
+pub fn node_ref<E, C>(container: C) -> NodeRefAttr<E, C>
+where
+    E: ElementType,
+    C: NodeRefContainer<E>,
+{
+    NodeRefAttr {
+        container,
+        ty: PhantomData,
+    }
+}
+
+/// Retained state for [`NodeRefAttr`]. The container holds the element while it is mounted, and
+/// is emptied when it is unmounted or when this state is dropped.
+pub struct NodeRefState<E, C>
+where
+    E: ElementType,
+    C: NodeRefContainer<E>,
+{
+    container: C,
+    el: types::Element,
+    key: DefaultKey,
+    ty: PhantomData<E>,
+}
+
+impl<E, C> Drop for NodeRefState<E, C>
+where
+    E: ElementType,
+    C: NodeRefContainer<E>,
+{
+    fn drop(&mut self) {
+        LiveRefs::remove(self.key);
+        self.container.clone().unload(&self.el);
+    }
+}
+
+type Loader = Rc<dyn Fn(bool)>;
+
+/// Fills `container` with `el` when called with `true`, and empties it when called with `false`.
+fn loader<E, C>(container: &C, el: &types::Element) -> Loader
+where
+    E: ElementType,
+    C: NodeRefContainer<E>,
+{
+    let container = container.clone();
+    let el = el.clone();
+    Rc::new(move |load| {
+        if load {
+            container.clone().load(&el);
+        } else {
+            container.clone().unload(&el);
+        }
+    })
+}
+
+struct LiveRef {
+    el: types::Element,
+    loaded: bool,
+    loader: Loader,
+}
+
+#[derive(Default)]
+struct Registry {
+    refs: SlotMap<DefaultKey, LiveRef>,
+    /// The refs of each element, so that those inside a subtree are found by walking it.
+    by_element: HashMap<usize, Vec<DefaultKey>>,
+}
+
+/// The node refs of all built elements, so that the refs inside a subtree can be emptied when it
+/// is unmounted and filled again when it is mounted.
+struct LiveRefs;
+
+thread_local! {
+    static LIVE_REFS: RefCell<Registry> = Default::default();
+}
+
+impl LiveRefs {
+    fn insert(el: &types::Element, loaded: bool, loader: Loader) -> DefaultKey {
+        LIVE_REFS.with_borrow_mut(|registry| {
+            let key = registry.refs.insert(LiveRef {
+                el: el.clone(),
+                loaded,
+                loader,
+            });
+            registry
+                .by_element
+                .entry(el.node_id())
+                .or_default()
+                .push(key);
+            key
+        })
+    }
+
+    fn set_loader(key: DefaultKey, loader: Loader) {
+        LIVE_REFS.with_borrow_mut(|registry| registry.refs[key].loader = loader);
+    }
+
+    fn is_loaded(key: DefaultKey) -> bool {
+        LIVE_REFS.with_borrow(|registry| registry.refs[key].loaded)
+    }
+
+    fn remove(key: DefaultKey) {
+        // the registry is gone if the state is dropped during thread teardown
+        let _ = LIVE_REFS.try_with(|registry| {
+            let registry = &mut *registry.borrow_mut();
+            let Some(live) = registry.refs.remove(key) else {
+                return;
+            };
+            let node_id = live.el.node_id();
+            if let Some(keys) = registry.by_element.get_mut(&node_id) {
+                keys.retain(|k| *k != key);
+                if keys.is_empty() {
+                    registry.by_element.remove(&node_id);
+                }
+            }
+        });
+    }
+
+    /// Fills or empties the refs of `root` and its descendants. The containers are only touched
+    /// after the registry is released, as filling them may build or drop other node refs.
+    fn set_loaded(root: &types::Element, load: bool) {
+        let loaders = LIVE_REFS.with_borrow_mut(|registry| {
+            if registry.by_element.is_empty() {
+                return Vec::new();
+            }
+
+            let doc = BlitzDocument::document();
+            let mut loaders = Vec::new();
+            let mut stack = vec![root.node_id()];
+            while let Some(node_id) = stack.pop() {
+                let Some(node) = doc.get_node(node_id) else {
+                    continue;
+                };
+                stack.extend(node.children.iter().rev());
+
+                for key in registry.by_element.get(&node_id).into_iter().flatten() {
+                    let live = &mut registry.refs[*key];
+                    if live.loaded != load {
+                        live.loaded = load;
+                        loaders.push(live.loader.clone());
+                    }
+                }
+            }
+            loaders
+        });
+        for loader in loaders {
+            loader(load);
+        }
+    }
+}
+
+/// Fills the node refs in the subtree of `el`, which has just been mounted. Nothing is filled
+/// while `el` is mounted into a parent that isn't in the document yet: the refs are filled once
+/// that parent is mounted.
+pub(crate) fn mount_node_refs(el: &types::Element) {
+    if el.is_connected() {
+        LiveRefs::set_loaded(el, true);
+    }
+}
+
+/// Empties the node refs in the subtree of `el`, which is about to be unmounted.
+pub(crate) fn unmount_node_refs(el: &types::Element) {
+    if el.is_connected() {
+        LiveRefs::set_loaded(el, false);
+    }
+}
+
+impl<E, C> Attribute for NodeRefAttr<E, C>
+where
+    E: ElementType + 'static,
+    C: NodeRefContainer<E>,
+{
+    const MIN_LENGTH: usize = 0;
+
+    type AsyncOutput = Self;
+    type State = NodeRefState<E, C>;
+    type Cloneable = Self;
+    type CloneableOwned = Self;
+
+    #[inline(always)]
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(
+        self,
+        _buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        _inner_html: &mut String,
+    ) {
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+        // hydrated elements are in the document already
+        self.container.clone().load(el);
+        NodeRefState {
+            key: LiveRefs::insert(el, true, loader(&self.container, el)),
+            container: self.container,
+            el: el.to_owned(),
+            ty: PhantomData,
+        }
+    }
+
+    fn build(self, el: &types::Element) -> Self::State {
+        // the container is filled once the element, or one of its ancestors, is mounted
+        NodeRefState {
+            key: LiveRefs::insert(el, false, loader(&self.container, el)),
+            container: self.container,
+            el: el.to_owned(),
+            ty: PhantomData,
+        }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        // the previous container no longer refers to this element
+        state.container.clone().unload(&state.el);
+        if LiveRefs::is_loaded(state.key) {
+            self.container.clone().load(&state.el);
+        }
+        LiveRefs::set_loader(state.key, loader(&self.container, &state.el));
+        state.container = self.container;
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+}
+
+impl<E, C> NextAttribute for NodeRefAttr<E, C>
+where
+    E: ElementType + 'static,
+    C: NodeRefContainer<E>,
+{
+    type Output<NewAttr: Attribute> = (Self, NewAttr);
+
+    fn add_any_attr<NewAttr: Attribute>(self, new_attr: NewAttr) -> Self::Output<NewAttr> {
+        (self, new_attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::_leptos_blitz::LeptosDocument;
+    use crate::_tachys::{
+        html::element::{div, span, ElementChild},
+        reactive_graph::node_ref::NodeRef,
+        renderer::types,
+        view::{either::EitherKeepAlive, Mountable, Render},
+    };
+    use reactive_graph::traits::GetUntracked;
+
+    fn loaded(node_ref: NodeRef<impl super::ElementType>) -> Option<usize> {
+        node_ref.get_untracked().map(|el| el.node_id())
+    }
+
+    #[test]
+    fn rebuilding_with_another_ref_empties_the_previous_one() {
+        let root = types::Element::from(LeptosDocument::install_document(None));
+        let (first, second) = (NodeRef::new(), NodeRef::new());
+
+        let mut state = div().node_ref(first).build();
+        assert_eq!(loaded(first), None, "built but not mounted yet");
+        state.mount(&root, None);
+        assert_eq!(loaded(first), Some(state.node_id()));
+
+        div().node_ref(second).rebuild(&mut state);
+        assert_eq!(loaded(first), None);
+        assert_eq!(loaded(second), Some(state.node_id()));
+
+        drop(state);
+        assert_eq!(loaded(second), None);
+    }
+
+    #[test]
+    fn refs_are_only_filled_in_the_document() {
+        let root = types::Element::from(LeptosDocument::install_document(None));
+        let inner = NodeRef::new();
+
+        // building mounts the span into the div, which isn't in the document yet
+        let mut state = div().child(span().node_ref(inner)).build();
+        assert_eq!(loaded(inner), None);
+
+        state.mount(&root, None);
+        assert!(loaded(inner).is_some());
+
+        state.unmount();
+        assert_eq!(loaded(inner), None);
+    }
+
+    #[test]
+    fn refs_are_emptied_while_unmounted() {
+        let root = types::Element::from(LeptosDocument::install_document(None));
+        let (inner, hidden) = (NodeRef::new(), NodeRef::new());
+        let view = |show_b| EitherKeepAlive {
+            a: Some(div().child(span().node_ref(inner))),
+            b: Some(span().node_ref(hidden)),
+            show_b,
+        };
+
+        let mut state = view(false).build();
+        state.mount(&root, None);
+        let inner_el = loaded(inner);
+        assert!(inner_el.is_some());
+        assert_eq!(loaded(hidden), None, "the other branch was never mounted");
+
+        for show_b in [true, false, true] {
+            view(show_b).rebuild(&mut state);
+            assert_eq!(loaded(inner).is_some(), !show_b);
+            assert_eq!(loaded(hidden).is_some(), show_b);
+        }
+
+        // the branch that is mounted again fills the ref with the same element
+        view(false).rebuild(&mut state);
+        assert_eq!(loaded(inner), inner_el);
+    }
+}
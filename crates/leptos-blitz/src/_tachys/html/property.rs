@@ -0,0 +1,366 @@
+use super::{
+    attribute::{Attribute, NextAttribute},
+    element::HtmlElement,
+};
+use crate::_tachys::renderer::{types, Rndr};
+use std::{borrow::Cow, sync::Arc};
+
+impl<E, At, Ch> HtmlElement<E, At, Ch>
+where
+    At: Attribute,
+{
+    /// Sets a DOM property, like `value` or `checked`, rather than an attribute.
+    pub fn prop<K, P>(
+        self,
+        key: K,
+        value: P,
+    ) -> HtmlElement<E, <At as NextAttribute>::Output<Property<K, P>>, Ch>
+    where
+        K: AsRef<str> + Send,
+        P: IntoProperty,
+        At: NextAttribute,
+        <At as NextAttribute>::Output<Property<K, P>>: Attribute,
+    {
+        let HtmlElement {
+            tag,
+            children,
+            attributes,
+            #[cfg(debug_assertions)]
+            defined_at,
+        } = self;
+        HtmlElement {
+            tag,
+            children,
+            attributes: attributes.add_any_attr(prop(key, value)),
+            #[cfg(debug_assertions)]
+            defined_at,
+        }
+    }
+}
+
+/// Creates an [`Attribute`] that will set a DOM property on an element.
+#[inline(always)]
+pub fn prop<K, P>(key: K, value: P) -> Property<K, P>
+where
+    K: AsRef<str>,
+    P: IntoProperty,
+{
+    Property { key, value }
+}
+
+/// An [`Attribute`] that will set a DOM property on an element.
+#[derive(Debug)]
+pub struct Property<K, P> {
+    key: K,
+    value: P,
+}
+
+impl<K, P> Clone for Property<K, P>
+where
+    K: Clone,
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl<K, P> Attribute for Property<K, P>
+where
+    K: AsRef<str> + Send,
+    P: IntoProperty,
+{
+    const MIN_LENGTH: usize = 0;
+
+    type AsyncOutput = Self;
+    type State = P::State;
+    type Cloneable = Property<Arc<str>, P::Cloneable>;
+    type CloneableOwned = Property<Arc<str>, P::CloneableOwned>;
+
+    #[inline(always)]
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(
+        self,
+        _buf: &mut String,
+        _class: &mut String,
+        _style: &mut String,
+        _inner_html: &mut String,
+    ) {
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+        self.value.hydrate::<FROM_SERVER>(el, self.key.as_ref())
+    }
+
+    fn build(self, el: &types::Element) -> Self::State {
+        self.value.build(el, self.key.as_ref())
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        self.value.rebuild(state, self.key.as_ref())
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        Property {
+            key: self.key.as_ref().into(),
+            value: self.value.into_cloneable(),
+        }
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        Property {
+            key: self.key.as_ref().into(),
+            value: self.value.into_cloneable_owned(),
+        }
+    }
+
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self
+    }
+}
+
+impl<K, P> NextAttribute for Property<K, P>
+where
+    K: AsRef<str> + Send,
+    P: IntoProperty,
+{
+    type Output<NewAttr: Attribute> = (Self, NewAttr);
+
+    fn add_any_attr<NewAttr: Attribute>(self, new_attr: NewAttr) -> Self::Output<NewAttr> {
+        (self, new_attr)
+    }
+}
+
+/// A possible value for a DOM property.
+pub trait IntoProperty: Send {
+    /// The view state retained between building and rebuilding.
+    type State;
+    /// An equivalent value that can be cloned.
+    type Cloneable: IntoProperty + Clone;
+    /// An equivalent value that can be cloned and is `'static`.
+    type CloneableOwned: IntoProperty + Clone + 'static;
+
+    /// Adds the property on an element created from HTML.
+    fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element, key: &str) -> Self::State;
+
+    /// Adds the property during client-side rendering.
+    fn build(self, el: &types::Element, key: &str) -> Self::State;
+
+    /// Updates the property with a new value.
+    fn rebuild(self, state: &mut Self::State, key: &str);
+
+    /// Converts this to a cloneable type.
+    fn into_cloneable(self) -> Self::Cloneable;
+
+    /// Converts this to a cloneable, owned type.
+    fn into_cloneable_owned(self) -> Self::CloneableOwned;
+}
+
+macro_rules! prop_type {
+    ($prop_type:ty) => {
+        impl IntoProperty for $prop_type {
+            type State = (types::Element, types::PropertyValue);
+            type Cloneable = Self;
+            type CloneableOwned = Self;
+
+            fn hydrate<const FROM_SERVER: bool>(
+                self,
+                el: &types::Element,
+                key: &str,
+            ) -> Self::State {
+                let value = self.into();
+                Rndr::set_property(el, key, &value);
+                (el.clone(), value)
+            }
+
+            fn build(self, el: &types::Element, key: &str) -> Self::State {
+                let value = self.into();
+                Rndr::set_property(el, key, &value);
+                (el.clone(), value)
+            }
+
+            fn rebuild(self, state: &mut Self::State, key: &str) {
+                let (el, prev) = state;
+                let value = self.into();
+                Rndr::set_property(el, key, &value);
+                *prev = value;
+            }
+
+            fn into_cloneable(self) -> Self::Cloneable {
+                self
+            }
+
+            fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                self
+            }
+        }
+
+        impl IntoProperty for Option<$prop_type> {
+            type State = (types::Element, types::PropertyValue);
+            type Cloneable = Self;
+            type CloneableOwned = Self;
+
+            fn hydrate<const FROM_SERVER: bool>(
+                self,
+                el: &types::Element,
+                key: &str,
+            ) -> Self::State {
+                let was_some = self.is_some();
+                let value = self.into();
+                if was_some {
+                    Rndr::set_property(el, key, &value);
+                }
+                (el.clone(), value)
+            }
+
+            fn build(self, el: &types::Element, key: &str) -> Self::State {
+                let was_some = self.is_some();
+                let value = self.into();
+                if was_some {
+                    Rndr::set_property(el, key, &value);
+                }
+                (el.clone(), value)
+            }
+
+            fn rebuild(self, state: &mut Self::State, key: &str) {
+                let (el, prev) = state;
+                let value = self.into();
+                Rndr::set_property(el, key, &value);
+                *prev = value;
+            }
+
+            fn into_cloneable(self) -> Self::Cloneable {
+                self
+            }
+
+            fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                self
+            }
+        }
+    };
+}
+
+macro_rules! prop_type_str {
+    ($prop_type:ty) => {
+        impl IntoProperty for $prop_type {
+            type State = (types::Element, types::PropertyValue);
+            type Cloneable = Arc<str>;
+            type CloneableOwned = Arc<str>;
+
+            fn hydrate<const FROM_SERVER: bool>(
+                self,
+                el: &types::Element,
+                key: &str,
+            ) -> Self::State {
+                let this: &str = &self;
+                let value = types::PropertyValue::from(this);
+                Rndr::set_property(el, key, &value);
+                (el.clone(), value)
+            }
+
+            fn build(self, el: &types::Element, key: &str) -> Self::State {
+                let this: &str = &self;
+                let value = types::PropertyValue::from(this);
+                Rndr::set_property(el, key, &value);
+                (el.clone(), value)
+            }
+
+            fn rebuild(self, state: &mut Self::State, key: &str) {
+                let (el, prev) = state;
+                let this: &str = &self;
+                let value = types::PropertyValue::from(this);
+                Rndr::set_property(el, key, &value);
+                *prev = value;
+            }
+
+            fn into_cloneable(self) -> Self::Cloneable {
+                let this: &str = &self;
+                this.into()
+            }
+
+            fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                let this: &str = &self;
+                this.into()
+            }
+        }
+
+        impl IntoProperty for Option<$prop_type> {
+            type State = (types::Element, types::PropertyValue);
+            type Cloneable = Option<Arc<str>>;
+            type CloneableOwned = Option<Arc<str>>;
+
+            fn hydrate<const FROM_SERVER: bool>(
+                self,
+                el: &types::Element,
+                key: &str,
+            ) -> Self::State {
+                let was_some = self.is_some();
+                let value = types::PropertyValue::from(self.as_ref().map(|n| -> &str { n }));
+                if was_some {
+                    Rndr::set_property(el, key, &value);
+                }
+                (el.clone(), value)
+            }
+
+            fn build(self, el: &types::Element, key: &str) -> Self::State {
+                let was_some = self.is_some();
+                let value = types::PropertyValue::from(self.as_ref().map(|n| -> &str { n }));
+                if was_some {
+                    Rndr::set_property(el, key, &value);
+                }
+                (el.clone(), value)
+            }
+
+            fn rebuild(self, state: &mut Self::State, key: &str) {
+                let (el, prev) = state;
+                let value = types::PropertyValue::from(self.as_ref().map(|n| -> &str { n }));
+                Rndr::set_property(el, key, &value);
+                *prev = value;
+            }
+
+            fn into_cloneable(self) -> Self::Cloneable {
+                self.map(|n| {
+                    let this: &str = &n;
+                    this.into()
+                })
+            }
+
+            fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                self.map(|n| {
+                    let this: &str = &n;
+                    this.into()
+                })
+            }
+        }
+    };
+}
+
+prop_type!(usize);
+prop_type!(u8);
+prop_type!(u16);
+prop_type!(u32);
+prop_type!(u64);
+prop_type!(u128);
+prop_type!(isize);
+prop_type!(i8);
+prop_type!(i16);
+prop_type!(i32);
+prop_type!(i64);
+prop_type!(i128);
+prop_type!(f32);
+prop_type!(f64);
+prop_type!(bool);
+
+prop_type_str!(Arc<str>);
+prop_type_str!(String);
+prop_type_str!(&String);
+prop_type_str!(&str);
+prop_type_str!(Cow<'_, str>);
@@ -1,18 +1,30 @@
 /// Commonly-used traits.
 pub mod prelude {
     pub use super::{
-        html::element::ElementChild,
+        html::{
+            attribute::{custom::CustomAttribute, IntoAttributeValue},
+            element::ElementChild,
+        },
         renderer::dom::Dom,
-        view::{add_attr::AddAnyAttr, IntoRender, Mountable, Render, RenderHtml},
+        view::{
+            add_attr::AddAnyAttr,
+            any_view::{AnyView, IntoAny},
+            fragment::{Fragment, IntoFragment},
+            IntoRender, Mountable, Render, RenderHtml,
+        },
     };
 }
 
 /// Types for building a statically-typed HTML view tree.
 pub mod html;
+/// Types for MathML.
+pub mod mathml;
 /// Defines various backends that can render views.
 pub mod renderer;
 /// Rendering views to HTML.
 pub mod ssr;
+/// Types for SVG.
+pub mod svg;
 /// Core logic for manipulating views.
 pub mod view;
 
@@ -0,0 +1,556 @@
+use super::{ReactiveFunction, SharedReactiveFunction};
+use crate::_tachys::{
+    html::class::IntoClass,
+    renderer::{types, Rndr},
+};
+use reactive_graph::effect::RenderEffect;
+use std::borrow::Borrow;
+
+/// Retained state for a reactive `class:name` toggle.
+pub struct RenderEffectWithClassName<T>
+where
+    T: 'static,
+{
+    name: &'static str,
+    effect: RenderEffect<T>,
+}
+
+impl<T> RenderEffectWithClassName<T>
+where
+    T: 'static,
+{
+    fn new(name: &'static str, effect: RenderEffect<T>) -> Self {
+        Self { effect, name }
+    }
+}
+
+impl<F, C> IntoClass for F
+where
+    F: ReactiveFunction<Output = C>,
+    C: IntoClass + 'static,
+    C::State: 'static,
+{
+    type AsyncOutput = C::AsyncOutput;
+    type State = RenderEffect<C::State>;
+    type Cloneable = SharedReactiveFunction<C>;
+    type CloneableOwned = SharedReactiveFunction<C>;
+
+    fn html_len(&self) -> usize {
+        0
+    }
+
+    fn to_html(mut self, class: &mut String) {
+        let value = self.invoke();
+        value.to_html(class);
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(mut self, el: &types::Element) -> Self::State {
+        // TODO FROM_SERVER vs template
+        let el = el.clone();
+        RenderEffect::new(move |prev| {
+            let value = self.invoke();
+            if let Some(mut state) = prev {
+                value.rebuild(&mut state);
+                state
+            } else {
+                value.hydrate::<FROM_SERVER>(&el)
+            }
+        })
+    }
+
+    fn build(mut self, el: &types::Element) -> Self::State {
+        let el = el.to_owned();
+        RenderEffect::new(move |prev| {
+            let value = self.invoke();
+            if let Some(mut state) = prev {
+                value.rebuild(&mut state);
+                state
+            } else {
+                value.build(&el)
+            }
+        })
+    }
+
+    fn rebuild(mut self, state: &mut Self::State) {
+        let prev_value = state.take_value();
+        *state = RenderEffect::new_with_value(
+            move |prev| {
+                let value = self.invoke();
+                if let Some(mut state) = prev {
+                    value.rebuild(&mut state);
+                    state
+                } else {
+                    unreachable!()
+                }
+            },
+            prev_value,
+        );
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        self.into_shared()
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        self.into_shared()
+    }
+
+    fn dry_resolve(&mut self) {
+        self.invoke().dry_resolve();
+    }
+
+    async fn resolve(mut self) -> Self::AsyncOutput {
+        self.invoke().resolve().await
+    }
+
+    fn reset(state: &mut Self::State) {
+        *state = RenderEffect::new_with_value(
+            move |prev| {
+                if let Some(mut state) = prev {
+                    C::reset(&mut state);
+                    state
+                } else {
+                    unreachable!()
+                }
+            },
+            state.take_value(),
+        );
+    }
+}
+
+impl<F, T> IntoClass for (&'static str, F)
+where
+    F: ReactiveFunction<Output = T>,
+    T: Borrow<bool> + Send + 'static,
+{
+    type AsyncOutput = (&'static str, bool);
+    type State = RenderEffectWithClassName<(types::ClassList, bool)>;
+    type Cloneable = (&'static str, SharedReactiveFunction<T>);
+    type CloneableOwned = (&'static str, SharedReactiveFunction<T>);
+
+    fn html_len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn to_html(self, class: &mut String) {
+        let (name, mut f) = self;
+        let include = *f.invoke().borrow();
+        if include {
+            <&str as IntoClass>::to_html(name, class);
+        }
+    }
+
+    fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+        // TODO FROM_SERVER vs template
+        let (name, mut f) = self;
+        let class_list = Rndr::class_list(el);
+        let name = Rndr::intern(name);
+
+        RenderEffectWithClassName::new(
+            name,
+            RenderEffect::new(move |prev: Option<(types::ClassList, bool)>| {
+                let include = *f.invoke().borrow();
+                if let Some((class_list, prev)) = prev {
+                    if include {
+                        if !prev {
+                            Rndr::add_class(&class_list, name);
+                        }
+                    } else if prev {
+                        Rndr::remove_class(&class_list, name);
+                    }
+                }
+                (class_list.clone(), include)
+            }),
+        )
+    }
+
+    fn build(self, el: &types::Element) -> Self::State {
+        let (name, mut f) = self;
+        let class_list = Rndr::class_list(el);
+        let name = Rndr::intern(name);
+
+        RenderEffectWithClassName::new(
+            name,
+            RenderEffect::new(move |prev: Option<(types::ClassList, bool)>| {
+                let include = *f.invoke().borrow();
+                match prev {
+                    Some((class_list, prev)) => {
+                        if include {
+                            if !prev {
+                                Rndr::add_class(&class_list, name);
+                            }
+                        } else if prev {
+                            Rndr::remove_class(&class_list, name);
+                        }
+                    }
+                    None => {
+                        if include {
+                            Rndr::add_class(&class_list, name);
+                        }
+                    }
+                }
+                (class_list.clone(), include)
+            }),
+        )
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let (name, mut f) = self;
+        // Name might've updated:
+        state.name = name;
+        state.effect = RenderEffect::new_with_value(
+            move |prev| {
+                let include = *f.invoke().borrow();
+                match prev {
+                    Some((class_list, prev)) => {
+                        if include {
+                            if !prev {
+                                Rndr::add_class(&class_list, name);
+                            }
+                        } else if prev {
+                            Rndr::remove_class(&class_list, name);
+                        }
+                        (class_list.clone(), include)
+                    }
+                    None => {
+                        unreachable!()
+                    }
+                }
+            },
+            state.effect.take_value(),
+        );
+    }
+
+    fn into_cloneable(self) -> Self::Cloneable {
+        (self.0, self.1.into_shared())
+    }
+
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {
+        (self.0, self.1.into_shared())
+    }
+
+    fn dry_resolve(&mut self) {
+        self.1.invoke();
+    }
+
+    async fn resolve(mut self) -> Self::AsyncOutput {
+        (self.0, *self.1.invoke().borrow())
+    }
+
+    fn reset(state: &mut Self::State) {
+        let name = state.name;
+        state.effect = RenderEffect::new_with_value(
+            move |prev| {
+                if let Some(mut state) = prev {
+                    let (class_list, prev) = &mut state;
+                    Rndr::remove_class(class_list, name);
+                    *prev = false;
+                    state
+                } else {
+                    unreachable!()
+                }
+            },
+            state.effect.take_value(),
+        );
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+mod stable {
+    use super::RenderEffectWithClassName;
+    use crate::_tachys::renderer::{types, Rndr};
+
+    macro_rules! class_signal_arena {
+        ($sig:ident) => {
+            #[allow(deprecated)]
+            impl<C, S> IntoClass for $sig<C, S>
+            where
+                $sig<C, S>: Get<Value = C>,
+                S: Send + Sync + 'static,
+                S: Storage<C> + Storage<Option<C>>,
+                C: IntoClass + Send + Sync + Clone + 'static,
+                C::State: 'static,
+            {
+                type AsyncOutput = Self;
+                type State = RenderEffect<C::State>;
+                type Cloneable = Self;
+                type CloneableOwned = Self;
+
+                fn html_len(&self) -> usize {
+                    0
+                }
+
+                fn to_html(self, class: &mut String) {
+                    let value = self.get();
+                    value.to_html(class);
+                }
+
+                fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+                    (move || self.get()).hydrate::<FROM_SERVER>(el)
+                }
+
+                fn build(self, el: &types::Element) -> Self::State {
+                    (move || self.get()).build(el)
+                }
+
+                fn rebuild(self, state: &mut Self::State) {
+                    (move || self.get()).rebuild(state)
+                }
+
+                fn into_cloneable(self) -> Self::Cloneable {
+                    self
+                }
+
+                fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                    self
+                }
+
+                fn dry_resolve(&mut self) {}
+
+                async fn resolve(self) -> Self::AsyncOutput {
+                    self
+                }
+
+                fn reset(state: &mut Self::State) {
+                    *state = RenderEffect::new_with_value(
+                        move |prev| {
+                            if let Some(mut state) = prev {
+                                C::reset(&mut state);
+                                state
+                            } else {
+                                unreachable!()
+                            }
+                        },
+                        state.take_value(),
+                    );
+                }
+            }
+
+            #[allow(deprecated)]
+            impl<S> IntoClass for (&'static str, $sig<bool, S>)
+            where
+                $sig<bool, S>: Get<Value = bool>,
+                S: Send + 'static,
+                S: Storage<bool>,
+            {
+                type AsyncOutput = Self;
+                type State = RenderEffectWithClassName<(types::ClassList, bool)>;
+                type Cloneable = Self;
+                type CloneableOwned = Self;
+
+                fn html_len(&self) -> usize {
+                    self.0.len()
+                }
+
+                fn to_html(self, class: &mut String) {
+                    let (name, f) = self;
+                    let include = f.get();
+                    if include {
+                        <&str as IntoClass>::to_html(name, class);
+                    }
+                }
+
+                fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+                    IntoClass::hydrate::<FROM_SERVER>((self.0, move || self.1.get()), el)
+                }
+
+                fn build(self, el: &types::Element) -> Self::State {
+                    IntoClass::build((self.0, move || self.1.get()), el)
+                }
+
+                fn rebuild(self, state: &mut Self::State) {
+                    IntoClass::rebuild((self.0, move || self.1.get()), state)
+                }
+
+                fn into_cloneable(self) -> Self::Cloneable {
+                    self
+                }
+
+                fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                    self
+                }
+
+                fn dry_resolve(&mut self) {}
+
+                async fn resolve(self) -> Self::AsyncOutput {
+                    self
+                }
+
+                fn reset(state: &mut Self::State) {
+                    let name = state.name;
+                    *state = RenderEffectWithClassName::new(
+                        state.name,
+                        RenderEffect::new_with_value(
+                            move |prev| {
+                                if let Some(mut state) = prev {
+                                    let (class_list, prev) = &mut state;
+                                    Rndr::remove_class(class_list, name);
+                                    *prev = false;
+                                    state
+                                } else {
+                                    unreachable!()
+                                }
+                            },
+                            state.effect.take_value(),
+                        ),
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! class_signal {
+        ($sig:ident) => {
+            impl<C> IntoClass for $sig<C>
+            where
+                $sig<C>: Get<Value = C>,
+                C: IntoClass + Send + Sync + Clone + 'static,
+                C::State: 'static,
+            {
+                type AsyncOutput = Self;
+                type State = RenderEffect<C::State>;
+                type Cloneable = Self;
+                type CloneableOwned = Self;
+
+                fn html_len(&self) -> usize {
+                    0
+                }
+
+                fn to_html(self, class: &mut String) {
+                    let value = self.get();
+                    value.to_html(class);
+                }
+
+                fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+                    (move || self.get()).hydrate::<FROM_SERVER>(el)
+                }
+
+                fn build(self, el: &types::Element) -> Self::State {
+                    (move || self.get()).build(el)
+                }
+
+                fn rebuild(self, state: &mut Self::State) {
+                    (move || self.get()).rebuild(state)
+                }
+
+                fn into_cloneable(self) -> Self::Cloneable {
+                    self
+                }
+
+                fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                    self
+                }
+
+                fn dry_resolve(&mut self) {}
+
+                async fn resolve(self) -> Self::AsyncOutput {
+                    self
+                }
+
+                fn reset(state: &mut Self::State) {
+                    *state = RenderEffect::new_with_value(
+                        move |prev| {
+                            if let Some(mut state) = prev {
+                                C::reset(&mut state);
+                                state
+                            } else {
+                                unreachable!()
+                            }
+                        },
+                        state.take_value(),
+                    );
+                }
+            }
+
+            impl IntoClass for (&'static str, $sig<bool>)
+            where
+                $sig<bool>: Get<Value = bool>,
+            {
+                type AsyncOutput = Self;
+                type State = RenderEffectWithClassName<(types::ClassList, bool)>;
+                type Cloneable = Self;
+                type CloneableOwned = Self;
+
+                fn html_len(&self) -> usize {
+                    self.0.len()
+                }
+
+                fn to_html(self, class: &mut String) {
+                    let (name, f) = self;
+                    let include = f.get();
+                    if include {
+                        <&str as IntoClass>::to_html(name, class);
+                    }
+                }
+
+                fn hydrate<const FROM_SERVER: bool>(self, el: &types::Element) -> Self::State {
+                    IntoClass::hydrate::<FROM_SERVER>((self.0, move || self.1.get()), el)
+                }
+
+                fn build(self, el: &types::Element) -> Self::State {
+                    IntoClass::build((self.0, move || self.1.get()), el)
+                }
+
+                fn rebuild(self, state: &mut Self::State) {
+                    IntoClass::rebuild((self.0, move || self.1.get()), state)
+                }
+
+                fn into_cloneable(self) -> Self::Cloneable {
+                    self
+                }
+
+                fn into_cloneable_owned(self) -> Self::CloneableOwned {
+                    self
+                }
+
+                fn dry_resolve(&mut self) {}
+
+                async fn resolve(self) -> Self::AsyncOutput {
+                    self
+                }
+
+                fn reset(state: &mut Self::State) {
+                    let name = state.name;
+                    *state = RenderEffectWithClassName::new(
+                        state.name,
+                        RenderEffect::new_with_value(
+                            move |prev| {
+                                if let Some(mut state) = prev {
+                                    let (class_list, prev) = &mut state;
+                                    Rndr::remove_class(class_list, name);
+                                    *prev = false;
+                                    state
+                                } else {
+                                    unreachable!()
+                                }
+                            },
+                            state.effect.take_value(),
+                        ),
+                    );
+                }
+            }
+        };
+    }
+
+    use super::RenderEffect;
+    use crate::_tachys::html::class::IntoClass;
+    #[allow(deprecated)]
+    use reactive_graph::wrappers::read::MaybeSignal;
+    use reactive_graph::{
+        computed::{ArcMemo, Memo},
+        owner::Storage,
+        signal::{ArcReadSignal, ArcRwSignal, ReadSignal, RwSignal},
+        traits::Get,
+        wrappers::read::{ArcSignal, Signal},
+    };
+
+    class_signal_arena!(RwSignal);
+    class_signal_arena!(ReadSignal);
+    class_signal_arena!(Memo);
+    class_signal_arena!(Signal);
+    class_signal_arena!(MaybeSignal);
+    class_signal!(ArcRwSignal);
+    class_signal!(ArcReadSignal);
+    class_signal!(ArcMemo);
+    class_signal!(ArcSignal);
+}
@@ -5,10 +5,23 @@ use crate::tachys::{
     view::{add_attr::AddAnyAttr, Mountable, Position, Render, RenderHtml},
 };
 use reactive_graph::effect::RenderEffect;
-use std::sync::{Arc, Mutex};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+mod class;
+mod inner_html;
+/// Provides a reactive [`NodeRef`](node_ref::NodeRef) type.
+pub mod node_ref;
+mod owned;
+mod property;
+mod style;
+mod suspense;
 
-// pub use owned::*;
-// pub use suspense::*;
+pub use owned::*;
+pub use suspense::*;
 
 // impl<F, V> ToTemplate for F
 // where
@@ -294,90 +307,67 @@ where
     }
 }
 
-// impl<V> AttributeValue for Suspend<V>
-// where
-//     V: AttributeValue + 'static,
-//     V::State: 'static,
-// {
-//     type State = Rc<RefCell<Option<V::State>>>;
-//     type AsyncOutput = V;
-//     type Cloneable = ();
-//     type CloneableOwned = ();
+impl<V> AttributeValue for Suspend<V>
+where
+    V: AttributeValue + 'static,
+    V::State: 'static,
+{
+    type State = Rc<RefCell<Option<V::State>>>;
+    type AsyncOutput = V;
+    type Cloneable = ();
+    type CloneableOwned = ();
 
-//     fn html_len(&self) -> usize {
-//         0
-//     }
+    fn html_len(&self) -> usize {
+        0
+    }
 
-//     fn to_html(self, _key: &str, _buf: &mut String) {
-//         #[cfg(feature = "tracing")]
-//         tracing::error!("Suspended attributes cannot be used outside Suspense.");
-//     }
+    fn to_html(self, _key: &str, _buf: &mut String) {}
 
-//     fn to_template(_key: &str, _buf: &mut String) {}
-
-//     fn hydrate<const FROM_SERVER: bool>(
-//         self,
-//         key: &str,
-//         el: &types::Element,
-//     ) -> Self::State {
-//         let key = key.to_owned();
-//         let el = el.to_owned();
-//         let state = Rc::new(RefCell::new(None));
-//         reactive_graph::spawn_local_scoped({
-//             let state = Rc::clone(&state);
-//             async move {
-//                 *state.borrow_mut() = Some(self.inner.await.hydrate::<FROM_SERVER>(&key, &el));
-//                 self.subscriber.forward();
-//             }
-//         });
-//         state
-//     }
+    fn to_template(_key: &str, _buf: &mut String) {}
 
-//     fn build(self, el: &types::Element, key: &str) -> Self::State {
-//         let key = key.to_owned();
-//         let el = el.to_owned();
-//         let state = Rc::new(RefCell::new(None));
-//         reactive_graph::spawn_local_scoped({
-//             let state = Rc::clone(&state);
-//             async move {
-//                 *state.borrow_mut() = Some(self.inner.await.build(&el, &key));
-//                 self.subscriber.forward();
-//             }
-//         });
-//         state
-//     }
+    fn hydrate<const FROM_SERVER: bool>(self, key: &str, el: &types::Element) -> Self::State {
+        self.build(el, key)
+    }
 
-//     fn rebuild(self, key: &str, state: &mut Self::State) {
-//         let key = key.to_owned();
-//         reactive_graph::spawn_local_scoped({
-//             let state = Rc::clone(state);
-//             async move {
-//                 let value = self.inner.await;
-//                 let mut state = state.borrow_mut();
-//                 if let Some(state) = state.as_mut() {
-//                     value.rebuild(&key, state);
-//                 }
-//                 self.subscriber.forward();
-//             }
-//         });
-//     }
+    fn build(self, el: &types::Element, key: &str) -> Self::State {
+        let key = key.to_owned();
+        let el = el.to_owned();
+        let state = Rc::new(RefCell::new(None));
+        reactive_graph::spawn_local_scoped({
+            let state = Rc::clone(&state);
+            async move {
+                *state.borrow_mut() = Some(self.inner.await.build(&el, &key));
+                self.subscriber.forward();
+            }
+        });
+        state
+    }
 
-//     fn into_cloneable(self) -> Self::Cloneable {
-//         #[cfg(feature = "tracing")]
-//         tracing::error!("Suspended attributes cannot be spread");
-//     }
+    fn rebuild(self, key: &str, state: &mut Self::State) {
+        let key = key.to_owned();
+        reactive_graph::spawn_local_scoped({
+            let state = Rc::clone(state);
+            async move {
+                let value = self.inner.await;
+                let mut state = state.borrow_mut();
+                if let Some(state) = state.as_mut() {
+                    value.rebuild(&key, state);
+                }
+                self.subscriber.forward();
+            }
+        });
+    }
 
-//     fn into_cloneable_owned(self) -> Self::CloneableOwned {
-//         #[cfg(feature = "tracing")]
-//         tracing::error!("Suspended attributes cannot be spread");
-//     }
+    fn into_cloneable(self) -> Self::Cloneable {}
 
-//     fn dry_resolve(&mut self) {}
+    fn into_cloneable_owned(self) -> Self::CloneableOwned {}
 
-//     async fn resolve(self) -> Self::AsyncOutput {
-//         self.inner.await
-//     }
-// }
+    fn dry_resolve(&mut self) {}
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        self.inner.await
+    }
+}
 
 /// A reactive function that can be shared across multiple locations and across threads.
 pub type SharedReactiveFunction<T> = Arc<Mutex<dyn FnMut() -> T + Send>>;
@@ -472,13 +462,15 @@ mod stable {
                 V: RenderHtml + Clone + Send + Sync + 'static,
                 V::State: 'static,
             {
-                type Output<SomeNewAttr: Attribute> = Self;
+                type Output<SomeNewAttr: Attribute> =
+                    Box<dyn FnMut() -> V::Output<SomeNewAttr::CloneableOwned> + Send>;
 
-                fn add_any_attr<NewAttr: Attribute>(self, _attr: NewAttr) -> Self::Output<NewAttr>
+                fn add_any_attr<NewAttr: Attribute>(self, attr: NewAttr) -> Self::Output<NewAttr>
                 where
                     Self::Output<NewAttr>: RenderHtml,
                 {
-                    todo!()
+                    let attr = attr.into_cloneable_owned();
+                    Box::new(move || self.get().add_any_attr(attr.clone()))
                 }
             }
 
@@ -627,13 +619,15 @@ mod stable {
                 V: RenderHtml + Clone + Send + Sync + 'static,
                 V::State: 'static,
             {
-                type Output<SomeNewAttr: Attribute> = $sig<V, S>;
+                type Output<SomeNewAttr: Attribute> =
+                    Box<dyn FnMut() -> V::Output<SomeNewAttr::CloneableOwned> + Send>;
 
-                fn add_any_attr<NewAttr: Attribute>(self, _attr: NewAttr) -> Self::Output<NewAttr>
+                fn add_any_attr<NewAttr: Attribute>(self, attr: NewAttr) -> Self::Output<NewAttr>
                 where
                     Self::Output<NewAttr>: RenderHtml,
                 {
-                    todo!()
+                    let attr = attr.into_cloneable_owned();
+                    Box::new(move || self.get().add_any_attr(attr.clone()))
                 }
             }
 
@@ -822,3 +816,64 @@ mod tests {
     }
 }
  */
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        _leptos::mount::mount_to, _leptos_blitz::LeptosDocument, either::Either, prelude::*,
+        tachys::html::class::class,
+    };
+    use blitz_web_api::dom::BlitzDocument;
+    use tokio::task::LocalSet;
+
+    /// Lets spawned tasks and queued effects run.
+    async fn settle() {
+        for _ in 0..32 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    /// The tag name and `class` attribute of the element mounted into `root`.
+    fn rendered(root: usize) -> (String, Option<String>) {
+        let doc = BlitzDocument::document();
+        let el = doc
+            .get_node(root)
+            .unwrap()
+            .children
+            .iter()
+            .find_map(|child| doc.get_node(*child)?.element_data());
+        let el = el.expect("an element is mounted");
+        let class = el.attrs.iter().find(|attr| &*attr.name.local == "class");
+        (
+            el.name.local.to_string(),
+            class.map(|attr| attr.value.clone()),
+        )
+    }
+
+    #[tokio::test]
+    async fn spread_attributes_are_added_to_the_rebuilt_view() {
+        LocalSet::new()
+            .run_until(async {
+                let root = LeptosDocument::install_document(None);
+                let (show_span, set_show_span) = signal(false);
+
+                let (_owner, _mountable) = mount_to(root.into(), move || {
+                    let view = move || {
+                        if show_span.get() {
+                            Either::Right(view! { <span>"span"</span> })
+                        } else {
+                            Either::Left(view! { <p>"p"</p> })
+                        }
+                    };
+                    view.add_any_attr(class("spread"))
+                });
+                settle().await;
+                assert_eq!(rendered(root), ("p".into(), Some("spread".into())));
+
+                set_show_span.set(true);
+                settle().await;
+                assert_eq!(rendered(root), ("span".into(), Some("spread".into())));
+            })
+            .await;
+    }
+}
@@ -0,0 +1,150 @@
+use crate::_tachys::{
+    html::{element::ElementType, node_ref::NodeRefContainer},
+    renderer::types,
+};
+use reactive_graph::{
+    effect::Effect,
+    graph::untrack,
+    signal::{
+        guards::{Derefable, ReadGuard},
+        RwSignal,
+    },
+    traits::{DefinedAt, Get, Notify, ReadUntracked, Track, UntrackableGuard, Update, Write},
+};
+use std::{cell::Cell, marker::PhantomData, ops::DerefMut};
+
+/// A reactive reference to a DOM element that can be used with the `node_ref` attribute.
+///
+/// It holds the element while it is mounted, and is emptied again when it is unmounted.
+#[derive(Debug)]
+pub struct NodeRef<E>(RwSignal<Option<types::Element>>, PhantomData<E>)
+where
+    E: ElementType;
+
+impl<E> NodeRef<E>
+where
+    E: ElementType,
+{
+    /// Creates a new node reference.
+    #[track_caller]
+    pub fn new() -> Self {
+        Self(RwSignal::new(None), PhantomData)
+    }
+
+    /// Runs the provided closure when the `NodeRef` has been connected
+    /// with its element.
+    #[inline(always)]
+    pub fn on_load<F>(self, f: F)
+    where
+        E: 'static,
+        F: FnOnce(types::Element) + 'static,
+    {
+        let f = Cell::new(Some(f));
+
+        Effect::new(move |_| {
+            if let Some(node_ref) = self.get() {
+                let f = f.take().unwrap();
+                untrack(move || {
+                    f(node_ref);
+                });
+            }
+        });
+    }
+}
+
+impl<E> Default for NodeRef<E>
+where
+    E: ElementType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Clone for NodeRef<E>
+where
+    E: ElementType,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for NodeRef<E> where E: ElementType {}
+
+impl<E> NodeRefContainer<E> for NodeRef<E>
+where
+    E: ElementType + Send + 'static,
+{
+    fn load(self, el: &types::Element) {
+        self.0.update(|current| *current = Some(el.clone()));
+    }
+
+    fn unload(self, el: &types::Element) {
+        // if the ref has since been moved to another element, that element keeps it
+        self.0.try_maybe_update(|current| {
+            let loaded = current
+                .as_ref()
+                .is_some_and(|current| current.node_id() == el.node_id());
+            if loaded {
+                *current = None;
+            }
+            (loaded, ())
+        });
+    }
+}
+
+impl<E> DefinedAt for NodeRef<E>
+where
+    E: ElementType,
+{
+    fn defined_at(&self) -> Option<&'static std::panic::Location<'static>> {
+        self.0.defined_at()
+    }
+}
+
+impl<E> Notify for NodeRef<E>
+where
+    E: ElementType,
+{
+    fn notify(&self) {
+        self.0.notify();
+    }
+}
+
+impl<E> Write for NodeRef<E>
+where
+    E: ElementType,
+{
+    type Value = Option<types::Element>;
+
+    fn try_write(&self) -> Option<impl UntrackableGuard<Target = Self::Value>> {
+        self.0.try_write()
+    }
+
+    fn try_write_untracked(&self) -> Option<impl DerefMut<Target = Self::Value>> {
+        self.0.try_write_untracked()
+    }
+}
+
+impl<E> ReadUntracked for NodeRef<E>
+where
+    E: ElementType,
+{
+    type Value = ReadGuard<Option<types::Element>, Derefable<Option<types::Element>>>;
+
+    fn try_read_untracked(&self) -> Option<Self::Value> {
+        Some(ReadGuard::new(Derefable(
+            self.0.try_read_untracked()?.as_ref().cloned(),
+        )))
+    }
+}
+
+impl<E> Track for NodeRef<E>
+where
+    E: ElementType,
+{
+    fn track(&self) {
+        self.0.track();
+    }
+}
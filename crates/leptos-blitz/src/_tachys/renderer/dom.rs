@@ -14,8 +14,9 @@ pub type Text = dom::Text;
 pub type Element = dom::Element;
 pub type Placeholder = dom::Comment;
 // pub type Event = wasm_bindgen::JsValue;
-// pub type ClassList = web_document::DomTokenList;
-// pub type CssStyleDeclaration = web_document::CssStyleDeclaration;
+pub type ClassList = dom::DomTokenList;
+pub type PropertyValue = dom::PropertyValue;
+pub type CssStyleDeclaration = dom::CssStyleDeclaration;
 // pub type TemplateElement = web_document::HtmlTemplateElement;
 
 impl Dom {
@@ -51,6 +52,13 @@ impl Dom {
         parent.insert_before(new_child, anchor);
     }
 
+    pub fn mount_before<M: Mountable>(new_child: &mut M, before: &Node) {
+        let parent =
+            Element::cast_from(Self::get_parent(before).expect("could not find parent element"))
+                .expect("placeholder parent should be Element");
+        new_child.mount(&parent, Some(before));
+    }
+
     pub fn remove_node(parent: &Element, child: &Node) -> Option<Node> {
         parent.remove_child(child)
     }
@@ -60,8 +68,36 @@ impl Dom {
     }
 
     pub fn remove_self(node: &Node) {
-        let doc = BlitzDocument::document_mut();
-        doc.remove_node(node.node_id());
+        node.remove();
+    }
+
+    pub fn set_property(el: &Element, key: &str, value: &PropertyValue) {
+        // like an expando property in the browser, an unsupported key has no effect
+        let _ = el.set_property(key, value);
+    }
+
+    pub fn class_list(el: &Element) -> ClassList {
+        el.class_list()
+    }
+
+    pub fn add_class(list: &ClassList, name: &str) {
+        list.add(name);
+    }
+
+    pub fn remove_class(list: &ClassList, name: &str) {
+        list.remove(name);
+    }
+
+    pub fn style(el: &Element) -> CssStyleDeclaration {
+        el.style()
+    }
+
+    pub fn set_css_property(style: &CssStyleDeclaration, name: &str, value: &str) {
+        style.set_property(name, value);
+    }
+
+    pub fn remove_css_property(style: &CssStyleDeclaration, name: &str) {
+        style.remove_property(name);
     }
 
     pub fn get_parent(node: &Node) -> Option<Node> {
@@ -86,11 +122,13 @@ impl Dom {
     }
 
     pub fn clear_children(parent: &Element) {
-        let doc = BlitzDocument::document_mut();
-        let parent = doc.get_node_mut(parent.node_id()).unwrap();
-        let children = parent.children.clone();
+        let children = BlitzDocument::document()
+            .get_node(parent.node_id())
+            .unwrap()
+            .children
+            .clone();
         for child in children.into_iter() {
-            doc.remove_node(child);
+            Node::from(child).remove();
         }
     }
 
@@ -103,12 +141,23 @@ impl Dom {
     // }
 
     pub fn create_element_from_html(html: &str) -> Element {
+        let tpl = Self::create_element("div", None);
+        Self::append_html(&tpl, html);
+        // Self::log_node(&tpl);
+        tpl
+    }
+
+    pub fn set_inner_html(el: &Element, html: &str) {
+        Self::clear_children(el);
+        Self::append_html(el, html);
+    }
+
+    /// Parses `html` and appends the resulting nodes to `parent`.
+    fn append_html(parent: &Element, html: &str) {
         use html5ever::parse_document;
-        use markup5ever::tendril::TendrilSink;
+        use markup5ever::{namespace_url, ns, tendril::TendrilSink};
         use markup5ever_rcdom::{Handle, NodeData, RcDom};
 
-        let tpl = Self::create_element("div", None);
-
         let dom: RcDom = parse_document(RcDom::default(), Default::default())
             .from_utf8()
             .read_from(&mut html.as_bytes())
@@ -131,11 +180,13 @@ impl Dom {
                         template_contents: _,
                         mathml_annotation_xml_integration_point: _,
                     } => {
+                        // keep the namespace the parser gave SVG and MathML subtrees
+                        let namespace = (name.ns != ns!(html)).then_some(&*name.ns);
                         let name: &str = &name.local;
                         let node = if ["html", "head", "body"].contains(&name) {
                             parent.clone()
                         } else {
-                            let node = document().create_element_ns(None, name);
+                            let node = document().create_element_ns(namespace, name);
                             for attr in attrs.borrow().iter() {
                                 node.set_attribute(&attr.name.local, &attr.value);
                             }
@@ -149,9 +200,7 @@ impl Dom {
                 }
             }
         }
-        traverse_dom(&tpl, &dom.document);
-        // Self::log_node(&tpl);
-        tpl
+        traverse_dom(parent, &dom.document);
     }
 }
 
@@ -1,7 +1,9 @@
 pub mod dom;
 
 pub mod types {
-    pub use super::dom::{Element, Node, Placeholder, Text};
+    pub use super::dom::{
+        ClassList, CssStyleDeclaration, Element, Node, Placeholder, PropertyValue, Text,
+    };
 }
 
 pub type Rndr = dom::Dom;
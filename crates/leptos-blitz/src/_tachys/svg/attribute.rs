@@ -0,0 +1,89 @@
+use crate::_tachys::html::attribute::{attributes, Attr, AttributeKey, AttributeValue};
+pub use crate::_tachys::html::attribute::{height, href, width, xmlns, Height, Href, Width, Xmlns};
+
+// These live apart from the HTML attributes so that single-letter keys like `d` and `x` don't
+// shadow the type parameters of the attribute tuple impls.
+attributes! {
+    /// The `clip-path` attribute references a `<clipPath>` that clips the element.
+    clip_path "clip-path",
+    /// The `clip-rule` attribute specifies how the inside of a clipping path is determined.
+    clip_rule "clip-rule",
+    /// The `cx` attribute specifies the x-coordinate of the center of a circle or ellipse.
+    cx "cx",
+    /// The `cy` attribute specifies the y-coordinate of the center of a circle or ellipse.
+    cy "cy",
+    /// The `d` attribute defines the path to be drawn.
+    d "d",
+    /// The `dx` attribute shifts text horizontally from its current position.
+    dx "dx",
+    /// The `dy` attribute shifts text vertically from its current position.
+    dy "dy",
+    /// The `fill` attribute specifies the paint used to fill the shape.
+    fill "fill",
+    /// The `fill-opacity` attribute specifies the opacity of the fill paint.
+    fill_opacity "fill-opacity",
+    /// The `fill-rule` attribute specifies how the inside of a shape is determined.
+    fill_rule "fill-rule",
+    /// The `font-family` attribute specifies the font family of text content.
+    font_family "font-family",
+    /// The `font-size` attribute specifies the font size of text content.
+    font_size "font-size",
+    /// The `gradientTransform` attribute specifies additional transformations applied to a gradient.
+    gradient_transform "gradientTransform",
+    /// The `gradientUnits` attribute specifies the coordinate system of a gradient.
+    gradient_units "gradientUnits",
+    /// The `offset` attribute specifies where a gradient stop is placed along the gradient vector.
+    offset "offset",
+    /// The `opacity` attribute specifies the opacity of the element.
+    opacity "opacity",
+    /// The `pathLength` attribute specifies the total length of the path, in user units.
+    path_length "pathLength",
+    /// The `points` attribute defines the list of points of a polyline or polygon.
+    points "points",
+    /// The `preserveAspectRatio` attribute specifies how the element is scaled to fit its viewport.
+    preserve_aspect_ratio "preserveAspectRatio",
+    /// The `r` attribute specifies the radius of a circle.
+    r "r",
+    /// The `rx` attribute specifies the horizontal radius of an ellipse or rounded rectangle.
+    rx "rx",
+    /// The `ry` attribute specifies the vertical radius of an ellipse or rounded rectangle.
+    ry "ry",
+    /// The `stop-color` attribute specifies the color of a gradient stop.
+    stop_color "stop-color",
+    /// The `stop-opacity` attribute specifies the opacity of a gradient stop.
+    stop_opacity "stop-opacity",
+    /// The `stroke` attribute specifies the paint used to draw the outline of the shape.
+    stroke "stroke",
+    /// The `stroke-dasharray` attribute specifies the pattern of dashes and gaps used to draw the outline.
+    stroke_dasharray "stroke-dasharray",
+    /// The `stroke-dashoffset` attribute specifies the offset of the dash pattern.
+    stroke_dashoffset "stroke-dashoffset",
+    /// The `stroke-linecap` attribute specifies the shape used at the ends of open subpaths.
+    stroke_linecap "stroke-linecap",
+    /// The `stroke-linejoin` attribute specifies the shape used at the corners of paths.
+    stroke_linejoin "stroke-linejoin",
+    /// The `stroke-miterlimit` attribute specifies the limit on the ratio of the miter length to the stroke width.
+    stroke_miterlimit "stroke-miterlimit",
+    /// The `stroke-opacity` attribute specifies the opacity of the stroke paint.
+    stroke_opacity "stroke-opacity",
+    /// The `stroke-width` attribute specifies the width of the stroke.
+    stroke_width "stroke-width",
+    /// The `text-anchor` attribute specifies how text is aligned relative to its position.
+    text_anchor "text-anchor",
+    /// The `transform` attribute specifies transformations applied to the element and its children.
+    transform "transform",
+    /// The `viewBox` attribute defines the position and dimension of the viewport in user space.
+    view_box "viewBox",
+    /// The `x` attribute specifies the x-coordinate of the element.
+    x "x",
+    /// The `x1` attribute specifies the x-coordinate of the start of a line or gradient vector.
+    x1 "x1",
+    /// The `x2` attribute specifies the x-coordinate of the end of a line or gradient vector.
+    x2 "x2",
+    /// The `y` attribute specifies the y-coordinate of the element.
+    y "y",
+    /// The `y1` attribute specifies the y-coordinate of the start of a line or gradient vector.
+    y1 "y1",
+    /// The `y2` attribute specifies the y-coordinate of the end of a line or gradient vector.
+    y2 "y2",
+}
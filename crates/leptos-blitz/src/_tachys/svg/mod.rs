@@ -0,0 +1,196 @@
+use crate::_tachys::{
+    html::{
+        attribute::{Attr, Attribute, AttributeValue, NextAttribute},
+        element::{ElementType, ElementWithChildren, HtmlElement},
+    },
+    renderer::types,
+    view::Render,
+};
+
+/// Types for SVG attributes.
+pub mod attribute;
+
+/// The SVG namespace, which SVG elements are created in.
+pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+macro_rules! svg_elements {
+	($($tag:ident [$($attr:ty),*]),* $(,)?) => {
+        paste::paste! {
+            $(
+                /// An SVG element.
+                // `tag()` function
+                #[allow(non_snake_case)]
+                #[track_caller]
+                pub fn $tag() -> HtmlElement<[<$tag:camel>], (), ()>
+                where
+                {
+                    HtmlElement {
+                        #[cfg(debug_assertions)]
+                        defined_at: std::panic::Location::caller(),
+                        tag: [<$tag:camel>],
+                        attributes: (),
+                        children: (),
+                    }
+                }
+
+                /// An SVG element.
+                #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+                pub struct [<$tag:camel>];
+
+                // Typed attribute methods
+                impl<At, Ch> HtmlElement<[<$tag:camel>], At, Ch>
+                where
+                    At: Attribute,
+                    Ch: Render,
+                {
+                    $(
+                        #[doc = concat!("The `", stringify!($attr), "` attribute on `<", stringify!($tag), ">`.")]
+                        pub fn $attr<V>(self, value: V) -> HtmlElement <
+                            [<$tag:camel>],
+                            <At as NextAttribute>::Output<Attr<$crate::_tachys::svg::attribute::[<$attr:camel>], V>>,
+                            Ch
+                        >
+                        where
+                            V: AttributeValue,
+                            At: NextAttribute,
+                            <At as NextAttribute>::Output<Attr<$crate::_tachys::svg::attribute::[<$attr:camel>], V>>: Attribute,
+                        {
+                            let HtmlElement {
+                                #[cfg(debug_assertions)]
+                                defined_at,
+                                tag,
+                                children,
+                                attributes
+                            } = self;
+                            HtmlElement {
+                                #[cfg(debug_assertions)]
+                                defined_at,
+                                tag,
+                                children,
+                                attributes: attributes.add_any_attr($crate::_tachys::svg::attribute::$attr(value)),
+                            }
+                        }
+                    )*
+                }
+
+                impl ElementType for [<$tag:camel>] {
+                    type Output = types::Element;
+
+                    const TAG: &'static str = stringify!($tag);
+                    const SELF_CLOSING: bool = false;
+                    const ESCAPE_CHILDREN: bool = true;
+                    const NAMESPACE: Option<&'static str> = Some(SVG_NAMESPACE);
+
+                    #[inline(always)]
+                    fn tag(&self) -> &str {
+                        Self::TAG
+                    }
+                }
+
+                impl ElementWithChildren for [<$tag:camel>] {}
+            )*
+		}
+    }
+}
+
+svg_elements![
+    a [],
+    animate [],
+    animateMotion [],
+    animateTransform [],
+    circle [cx, cy, r, fill, stroke, stroke_width, opacity, transform],
+    clipPath [transform],
+    defs [],
+    desc [],
+    discard [],
+    ellipse [cx, cy, rx, ry, fill, stroke, stroke_width, opacity, transform],
+    feBlend [],
+    feColorMatrix [],
+    feComponentTransfer [],
+    feComposite [],
+    feConvolveMatrix [],
+    feDiffuseLighting [],
+    feDisplacementMap [],
+    feDistantLight [],
+    feDropShadow [],
+    feFlood [],
+    feFuncA [],
+    feFuncB [],
+    feFuncG [],
+    feFuncR [],
+    feGaussianBlur [],
+    feImage [],
+    feMerge [],
+    feMergeNode [],
+    feMorphology [],
+    feOffset [],
+    fePointLight [],
+    feSpecularLighting [],
+    feSpotLight [],
+    feTile [],
+    feTurbulence [],
+    filter [],
+    foreignObject [],
+    g [fill, stroke, stroke_width, opacity, transform, clip_path],
+    hatch [],
+    hatchpath [],
+    image [href, x, y, width, height, preserve_aspect_ratio],
+    line [x1, y1, x2, y2, stroke, stroke_width, stroke_linecap, stroke_dasharray, opacity, transform],
+    linearGradient [x1, y1, x2, y2, gradient_units, gradient_transform],
+    marker [],
+    mask [],
+    metadata [],
+    mpath [],
+    path [d, fill, fill_rule, clip_rule, stroke, stroke_width, stroke_linecap, stroke_linejoin, path_length, opacity, transform],
+    pattern [],
+    polygon [points, fill, fill_rule, stroke, stroke_width, stroke_linejoin, opacity, transform],
+    polyline [points, fill, stroke, stroke_width, stroke_linecap, stroke_linejoin, opacity, transform],
+    radialGradient [cx, cy, r, gradient_units, gradient_transform],
+    rect [x, y, width, height, rx, ry, fill, stroke, stroke_width, opacity, transform],
+    script [],
+    set [],
+    stop [offset, stop_color, stop_opacity],
+    style [],
+    svg [view_box, width, height, x, y, xmlns, preserve_aspect_ratio, fill, stroke, stroke_width],
+    switch [],
+    symbol [],
+    text [x, y, dx, dy, fill, font_family, font_size, text_anchor, transform],
+    textPath [],
+    title [],
+    tspan [x, y, dx, dy, fill],
+    view [],
+];
+
+/// An SVG element.
+#[allow(non_snake_case)]
+#[track_caller]
+pub fn r#use() -> HtmlElement<Use, (), ()>
+where {
+    HtmlElement {
+        #[cfg(debug_assertions)]
+        defined_at: std::panic::Location::caller(),
+        tag: Use,
+        attributes: (),
+        children: (),
+    }
+}
+
+/// An SVG element.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Use;
+
+impl ElementType for Use {
+    type Output = types::Element;
+
+    const TAG: &'static str = "use";
+    const SELF_CLOSING: bool = false;
+    const ESCAPE_CHILDREN: bool = true;
+    const NAMESPACE: Option<&'static str> = Some(SVG_NAMESPACE);
+
+    #[inline(always)]
+    fn tag(&self) -> &str {
+        Self::TAG
+    }
+}
+
+impl ElementWithChildren for Use {}
@@ -0,0 +1,349 @@
+use super::{add_attr::AddAnyAttr, Mountable, Position, Render, RenderHtml};
+use crate::_tachys::{
+    html::attribute::{
+        any_attribute::{AnyAttribute, IntoAnyAttribute},
+        Attribute,
+    },
+    renderer::types,
+    ssr::StreamBuilder,
+};
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    future::Future,
+    mem,
+    pin::Pin,
+};
+
+/// A type-erased view. This can be used if control flow requires that multiple different types of
+/// view must be received, and it is either impossible or too cumbersome to use the `EitherOf___`
+/// enums.
+///
+/// It can also be used to create recursive components, which otherwise cannot return themselves
+/// due to the static typing of the view tree.
+///
+/// Generally speaking, using `AnyView` restricts the amount of information available to the
+/// compiler and should be limited to situations in which it is necessary to preserve the maximum
+/// amount of type information possible.
+pub struct AnyView {
+    type_id: TypeId,
+    value: Box<dyn Any + Send>,
+    build: fn(Box<dyn Any>) -> AnyViewState,
+    rebuild: fn(TypeId, Box<dyn Any>, &mut AnyViewState),
+    // attributes spread onto the view are held here, and only added to the inner view once it is
+    // rendered: erasing the inner view again every time an attribute is added would make the
+    // compiler instantiate an ever-growing attribute type
+    attrs: Vec<AnyAttribute>,
+    add_any_attr: Option<AddAnyAttrFn>,
+    html_len: usize,
+    to_html: fn(Box<dyn Any>, &mut String, &mut Position, bool, bool),
+    to_html_async: fn(Box<dyn Any>, &mut StreamBuilder, &mut Position, bool, bool),
+    to_html_async_ooo: fn(Box<dyn Any>, &mut StreamBuilder, &mut Position, bool, bool),
+    #[allow(clippy::type_complexity)]
+    resolve: fn(Box<dyn Any>) -> Pin<Box<dyn Future<Output = AnyView> + Send>>,
+    dry_resolve: fn(&mut Box<dyn Any + Send>),
+}
+
+type AddAnyAttrFn = fn(Box<dyn Any>, Vec<AnyAttribute>) -> AnyView;
+
+/// Retained view state for [`AnyView`].
+pub struct AnyViewState {
+    type_id: TypeId,
+    state: Box<dyn Any>,
+    unmount: fn(&mut dyn Any),
+    mount: fn(&mut dyn Any, parent: &types::Element, marker: Option<&types::Node>),
+    insert_before_this: fn(&dyn Any, child: &mut dyn Mountable) -> bool,
+}
+
+impl Debug for AnyViewState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnyViewState")
+            .field("type_id", &self.type_id)
+            .field("state", &self.state)
+            .field("unmount", &self.unmount)
+            .field("mount", &self.mount)
+            .field("insert_before_this", &self.insert_before_this)
+            .finish()
+    }
+}
+
+/// Allows converting some view into [`AnyView`].
+pub trait IntoAny {
+    /// Converts the view into a type-erased [`AnyView`].
+    fn into_any(self) -> AnyView;
+}
+
+fn mount_any<T>(state: &mut dyn Any, parent: &types::Element, marker: Option<&types::Node>)
+where
+    T: Render,
+    T::State: 'static,
+{
+    let state = state
+        .downcast_mut::<T::State>()
+        .expect("AnyViewState::as_mountable couldn't downcast state");
+    state.mount(parent, marker)
+}
+
+fn unmount_any<T>(state: &mut dyn Any)
+where
+    T: Render,
+    T::State: 'static,
+{
+    let state = state
+        .downcast_mut::<T::State>()
+        .expect("AnyViewState::unmount couldn't downcast state");
+    state.unmount();
+}
+
+fn insert_before_this<T>(state: &dyn Any, child: &mut dyn Mountable) -> bool
+where
+    T: Render,
+    T::State: 'static,
+{
+    let state = state
+        .downcast_ref::<T::State>()
+        .expect("AnyViewState::insert_before_this couldn't downcast state");
+    state.insert_before_this(child)
+}
+
+impl<T> IntoAny for T
+where
+    T: Send,
+    T: RenderHtml + 'static,
+    T::State: 'static,
+{
+    fn into_any(self) -> AnyView {
+        let value = Box::new(self) as Box<dyn Any + Send>;
+
+        match value.downcast::<AnyView>() {
+            // if it's already an AnyView, we don't need to double-wrap it
+            Ok(any_view) => *any_view,
+            Err(value) => {
+                let value = value
+                    .downcast::<T>()
+                    .expect("AnyView::into_any could not be downcast");
+                erase(*value, Some(add_any_attr::<T>))
+            }
+        }
+    }
+}
+
+/// Adds the spread attributes to the inner view, and erases the result. The new view can't take
+/// any more attributes, which is what stops this from recursing.
+fn add_any_attr<T>(value: Box<dyn Any>, attrs: Vec<AnyAttribute>) -> AnyView
+where
+    T: RenderHtml + 'static,
+    T::State: 'static,
+{
+    let value = value
+        .downcast::<T>()
+        .expect("AnyView::add_any_attr could not be downcast");
+    erase(value.add_any_attr(attrs), None)
+}
+
+fn erase<T>(value: T, add_any_attr: Option<AddAnyAttrFn>) -> AnyView
+where
+    T: RenderHtml + 'static,
+    T::State: 'static,
+{
+    let html_len = value.html_len();
+
+    let value = Box::new(value) as Box<dyn Any + Send>;
+
+    let dry_resolve = |value: &mut Box<dyn Any + Send>| {
+        let value = value
+            .downcast_mut::<T>()
+            .expect("AnyView::resolve could not be downcast");
+        value.dry_resolve();
+    };
+
+    let resolve = |value: Box<dyn Any>| {
+        let value = value
+            .downcast::<T>()
+            .expect("AnyView::resolve could not be downcast");
+        Box::pin(async move { erase(value.resolve().await, None) })
+            as Pin<Box<dyn Future<Output = AnyView> + Send>>
+    };
+    let to_html = |value: Box<dyn Any>,
+                   buf: &mut String,
+                   position: &mut Position,
+                   escape: bool,
+                   mark_branches: bool| {
+        let value = value
+            .downcast::<T>()
+            .expect("AnyView::to_html could not be downcast");
+        value.to_html_with_buf(buf, position, escape, mark_branches);
+    };
+    let to_html_async = |value: Box<dyn Any>,
+                         buf: &mut StreamBuilder,
+                         position: &mut Position,
+                         escape: bool,
+                         mark_branches: bool| {
+        let value = value
+            .downcast::<T>()
+            .expect("AnyView::to_html could not be downcast");
+        value.to_html_async_with_buf::<false>(buf, position, escape, mark_branches);
+    };
+    let to_html_async_ooo = |value: Box<dyn Any>,
+                             buf: &mut StreamBuilder,
+                             position: &mut Position,
+                             escape: bool,
+                             mark_branches: bool| {
+        let value = value
+            .downcast::<T>()
+            .expect("AnyView::to_html could not be downcast");
+        value.to_html_async_with_buf::<true>(buf, position, escape, mark_branches);
+    };
+    let build = |value: Box<dyn Any>| {
+        let value = value
+            .downcast::<T>()
+            .expect("AnyView::build couldn't downcast");
+        let state = Box::new(value.build());
+
+        AnyViewState {
+            type_id: TypeId::of::<T>(),
+            state,
+
+            mount: mount_any::<T>,
+            unmount: unmount_any::<T>,
+            insert_before_this: insert_before_this::<T>,
+        }
+    };
+
+    let rebuild = |new_type_id: TypeId, value: Box<dyn Any>, state: &mut AnyViewState| {
+        let value = value
+            .downcast::<T>()
+            .expect("AnyView::rebuild couldn't downcast value");
+        if new_type_id == state.type_id {
+            let state = state
+                .state
+                .downcast_mut()
+                .expect("AnyView::rebuild couldn't downcast state");
+            value.rebuild(state);
+        } else {
+            let mut new = erase(*value, None).build();
+            state.insert_before_this(&mut new);
+            state.unmount();
+            *state = new;
+        }
+    };
+
+    AnyView {
+        type_id: TypeId::of::<T>(),
+        value,
+        build,
+        rebuild,
+        attrs: Vec::new(),
+        add_any_attr,
+        resolve,
+        dry_resolve,
+        html_len,
+        to_html,
+        to_html_async,
+        to_html_async_ooo,
+    }
+}
+
+impl AnyView {
+    /// Adds any attributes that have been spread onto this view to the inner view.
+    fn with_attrs(mut self) -> Self {
+        if self.attrs.is_empty() {
+            return self;
+        }
+        match self.add_any_attr {
+            Some(add_any_attr) => {
+                let attrs = mem::take(&mut self.attrs);
+                add_any_attr(self.value, attrs)
+            }
+            None => self,
+        }
+    }
+}
+
+impl Render for AnyView {
+    type State = AnyViewState;
+
+    fn build(self) -> Self::State {
+        let this = self.with_attrs();
+        (this.build)(this.value)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        let this = self.with_attrs();
+        (this.rebuild)(this.type_id, this.value, state)
+    }
+}
+
+impl AddAnyAttr for AnyView {
+    type Output<SomeNewAttr: Attribute> = Self;
+
+    fn add_any_attr<NewAttr: Attribute>(mut self, attr: NewAttr) -> Self::Output<NewAttr>
+    where
+        Self::Output<NewAttr>: RenderHtml,
+    {
+        self.attrs.push(attr.into_any_attr());
+        self
+    }
+}
+
+impl RenderHtml for AnyView {
+    type AsyncOutput = Self;
+
+    fn dry_resolve(&mut self) {
+        (self.dry_resolve)(&mut self.value);
+        self.attrs.dry_resolve();
+    }
+
+    async fn resolve(self) -> Self::AsyncOutput {
+        let this = self.with_attrs();
+        (this.resolve)(this.value).await
+    }
+
+    const MIN_LENGTH: usize = 0;
+
+    fn to_html_with_buf(
+        self,
+        buf: &mut String,
+        position: &mut Position,
+        escape: bool,
+        mark_branches: bool,
+    ) {
+        let this = self.with_attrs();
+        (this.to_html)(this.value, buf, position, escape, mark_branches);
+    }
+
+    fn to_html_async_with_buf<const OUT_OF_ORDER: bool>(
+        self,
+        buf: &mut StreamBuilder,
+        position: &mut Position,
+        escape: bool,
+        mark_branches: bool,
+    ) where
+        Self: Sized,
+    {
+        let this = self.with_attrs();
+        if OUT_OF_ORDER {
+            (this.to_html_async_ooo)(this.value, buf, position, escape, mark_branches);
+        } else {
+            (this.to_html_async)(this.value, buf, position, escape, mark_branches);
+        }
+    }
+
+    fn html_len(&self) -> usize {
+        self.html_len + self.attrs.html_len()
+    }
+}
+
+impl Mountable for AnyViewState {
+    fn unmount(&mut self) {
+        (self.unmount)(&mut *self.state)
+    }
+
+    fn mount(&mut self, parent: &types::Element, marker: Option<&types::Node>) {
+        (self.mount)(&mut *self.state, parent, marker)
+    }
+
+    fn insert_before_this(&self, child: &mut dyn Mountable) -> bool {
+        (self.insert_before_this)(&*self.state, child)
+    }
+}
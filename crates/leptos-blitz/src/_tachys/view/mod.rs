@@ -5,6 +5,16 @@ use std::{future::Future, sync::Arc};
 
 /// Add attributes to typed views.
 pub mod add_attr;
+/// A typed-erased view type.
+pub mod any_view;
+/// Allows choosing between one of several views.
+pub mod either;
+/// A type-erased view collection.
+pub mod fragment;
+/// View implementations for several iterable types.
+pub mod iterators;
+/// Keyed list iteration.
+pub mod keyed;
 mod primitives;
 /// View implementation for string types.
 pub mod strings;
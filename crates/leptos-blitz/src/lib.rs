@@ -1,13 +1,21 @@
+extern crate self as leptos_blitz;
+
 mod _leptos;
 mod _leptos_blitz;
+mod _leptos_dom;
 mod _tachys;
 
 /// Exports all the core types of the library.
 pub mod prelude {
     pub use super::_tachys::prelude::*;
+    pub use super::_tachys::reactive_graph::{node_ref::*, Suspend};
     pub use reactive_graph::prelude::*;
 
-    pub use super::_leptos::into_view::*;
+    pub use super::_leptos::{
+        await_::*, children::*, component::*, for_loop::*, into_view::*, suspense_component::*,
+        transition::*,
+    };
+    pub use super::_leptos_dom::helpers::*;
     pub use super::_leptos_blitz::launch;
     pub use leptos_blitz_macro::*;
     pub use reactive_graph::{
@@ -21,7 +29,23 @@ pub mod tachys {
 }
 pub use reactive_graph as reactive;
 
+/// Types that can be passed as the `children` prop of a component.
+pub use _leptos::children;
+/// Traits used to implement component constructors.
+pub use _leptos::component;
+
+#[doc(hidden)]
+pub use typed_builder;
+#[doc(hidden)]
+pub use typed_builder_macro;
+
+/// Types for choosing between one of several views.
+pub use either_of as either;
 /// HTML element types.
 pub use _tachys::html::element as html;
+/// MathML element types.
+pub use _tachys::mathml as math;
+/// SVG element types.
+pub use _tachys::svg;
 /// HTML event types.
 pub use _tachys::html::event as ev;
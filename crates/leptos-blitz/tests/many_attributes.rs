@@ -0,0 +1,80 @@
+use leptos_blitz::prelude::*;
+
+// A single element with well over 26 attributes, event handlers and bindings: attribute tuples
+// used to stop at 26 entries.
+#[test]
+fn element_with_many_attributes() {
+    let value = RwSignal::new(String::from("42x"));
+    let focused = RwSignal::new(false);
+    let invalid = move || value.with(|value| value.parse::<u32>().is_err());
+
+    let view = view! {
+        <main>
+            <input
+                formnovalidate=true
+                readonly=false
+                accept="text/plain"
+                alt="Quantity"
+                autocomplete="off"
+                form="order"
+                formaction="/order"
+                formenctype="text/plain"
+                formmethod="post"
+                formtarget="_self"
+                height="24"
+                list="quantities"
+                max="100"
+                maxlength="3"
+                min="0"
+                minlength="1"
+                name="quantity"
+                pattern="[0-9]+"
+                placeholder="Quantity"
+                required=true
+                size="8"
+                step="1"
+                type="text"
+                width="120"
+                data-field="quantity"
+                data-section="order"
+                data-row="1"
+                data-column="2"
+                data-unit="pieces"
+                data-testid="quantity-input"
+                class="field"
+                class:focused=move || focused.get()
+                class:invalid=invalid
+                style:border-width="2px"
+                style:border-color=move || if invalid() { "crimson" } else { "seagreen" }
+                style:padding="4px"
+                prop:value=move || value.get()
+                prop:disabled=false
+                on:input=move |ev| {
+                    if let Some(target) = ev.target() {
+                        value.set(target.value());
+                    }
+                }
+                on:focus=move |_| focused.set(true)
+                on:blur=move |_| focused.set(false)
+            />
+            <p>{move || if invalid() { "Enter a whole number." } else { "" }}</p>
+        </main>
+    };
+
+    let html = view.to_html();
+    assert!(html.starts_with(r#"<main><input formnovalidate accept="text/plain" alt="Quantity""#));
+    assert!(html.contains(r#" data-unit="pieces" data-testid="quantity-input" "#));
+    let class = html
+        .split(r#"class=""#)
+        .nth(1)
+        .unwrap()
+        .split('"')
+        .next()
+        .unwrap();
+    assert_eq!(
+        class.split_whitespace().collect::<Vec<_>>(),
+        ["field", "invalid"]
+    );
+    assert!(html.contains(r#"style="border-width:2px;border-color:crimson;padding:4px;""#));
+    assert!(html.ends_with("><p>Enter a whole number.</p></main>"));
+}
@@ -274,8 +274,8 @@ impl ToTokens for Model {
             body_expr
         } else if cfg!(erase_components) {
             quote! {
-                ::leptos::prelude::IntoAny::into_any(
-                    ::leptos::prelude::untrack(
+                ::leptos_blitz::prelude::IntoAny::into_any(
+                    ::leptos_blitz::prelude::untrack(
                         move || {
                             #tracing_guard_expr
                             #tracing_props_expr
@@ -286,7 +286,7 @@ impl ToTokens for Model {
             }
         } else {
             quote! {
-                ::leptos::prelude::untrack(
+                ::leptos_blitz::prelude::untrack(
                     move || {
                         #tracing_guard_expr
                         #tracing_props_expr
@@ -493,9 +493,9 @@ impl ToTokens for Model {
             #[doc = #builder_name_doc]
             #[doc = ""]
             #docs_and_prop_docs
-            #[derive(::leptos::typed_builder_macro::TypedBuilder #props_derive_serialize)]
+            #[derive(::leptos_blitz::typed_builder_macro::TypedBuilder #props_derive_serialize)]
             //#[builder(doc)]
-            #[builder(crate_module_path=::leptos::typed_builder)]
+            #[builder(crate_module_path=::leptos_blitz::typed_builder)]
             #[allow(non_snake_case)]
             #vis struct #props_name #impl_generics #where_clause {
                 #prop_builder_fields
@@ -506,7 +506,7 @@ impl ToTokens for Model {
             #[allow(missing_docs)]
             #binding
 
-            impl #impl_generics ::leptos::component::Props for #props_name #generics #where_clause {
+            impl #impl_generics ::leptos_blitz::component::Props for #props_name #generics #where_clause {
                 type Builder = #props_builder_name #generics;
 
                 fn builder() -> Self::Builder {
@@ -514,14 +514,6 @@ impl ToTokens for Model {
                 }
             }
 
-            // TODO restore dyn attrs
-            /*impl #impl_generics ::leptos::DynAttrs for #props_name #generics #where_clause {
-                fn dyn_attrs(mut self, v: Vec<(&'static str, ::leptos::Attribute)>) -> Self {
-                    #dyn_attrs_props
-                    self
-                }
-            } */
-
             #unknown_attrs
             #docs_and_prop_docs
             #[allow(non_snake_case, clippy::too_many_arguments)]
@@ -701,7 +693,7 @@ impl Docs {
         let mut quote_ws = "".to_string();
         let mut view_code_fence_state = ViewCodeFenceState::Outside;
         // todo fix docs stuff
-        const RSX_START: &str = "# ::leptos::view! {";
+        const RSX_START: &str = "# ::leptos_blitz::view! {";
         const RSX_END: &str = "# };";
 
         // Separated out of chain to allow rustfmt to work
@@ -79,8 +79,8 @@ impl ToTokens for Model {
             #[doc = ""]
             #docs
             #prop_docs
-            #[derive(::leptos::typed_builder_macro::TypedBuilder)]
-            #[builder(doc, crate_module_path=::leptos::typed_builder)]
+            #[derive(::leptos_blitz::typed_builder_macro::TypedBuilder)]
+            #[builder(doc, crate_module_path=::leptos_blitz::typed_builder)]
             #vis struct #name #generics #where_clause {
                 #prop_builder_fields
             }
@@ -90,20 +90,6 @@ impl ToTokens for Model {
                     vec![value]
                 }
             }
-
-            /*impl #impl_generics ::leptos::Props for #name #generics #where_clause {
-                type Builder = #builder_name #generics;
-                fn builder() -> Self::Builder {
-                    #name::builder()
-                }
-            }
-
-            impl #impl_generics ::leptos::DynAttrs for #name #generics #where_clause {
-                fn dyn_attrs(mut self, v: Vec<(&'static str, ::leptos::Attribute)>) -> Self {
-                    #dyn_attrs_props
-                    self
-                }
-            }*/
         };
 
         tokens.append_all(output)
@@ -234,7 +234,7 @@ pub(crate) fn component_to_tokens(
                     .children({
                         #(#clonables)*
 
-                        ::leptos::children::ToChildren::to_children(move || #children)
+                        ::leptos_blitz::children::ToChildren::to_children(move || #children)
                     })
                 }
             }
@@ -276,11 +276,11 @@ pub(crate) fn component_to_tokens(
             #[allow(unreachable_code)]
             #[allow(unused_mut)]
             #[allow(clippy::let_and_return)]
-            ::leptos::component::component_view(
+            ::leptos_blitz::component::component_view(
                 #[allow(clippy::needless_borrows_for_generic_args)]
                 &#name,
                 {
-                    let mut props = ::leptos::component::component_props_builder(&#name #generics)
+                    let mut props = ::leptos_blitz::component::component_props_builder(&#name #generics)
                         #(#required_props)*
                         #(#slots)*
                         #children
@@ -775,10 +775,10 @@ pub(crate) fn element_to_tokens(
             } else {
                 name.to_token_stream()
             };
-            quote_spanned! { node.name().span() => ::leptos::tachys::svg::#name() }
+            quote_spanned! { node.name().span() => ::leptos_blitz::tachys::svg::#name() }
         } else if is_math_ml_element(&tag) {
             parent_type = TagType::Math;
-            quote_spanned! { node.name().span() => ::leptos::tachys::mathml::#name() }
+            quote_spanned! { node.name().span() => ::leptos_blitz::tachys::mathml::#name() }
         } else if is_ambiguous_element(&tag) {
             match parent_type {
                 TagType::Unknown => {
@@ -793,10 +793,10 @@ pub(crate) fn element_to_tokens(
                     quote_spanned! { node.name().span() => ::leptos_blitz::tachys::html::element::#name() }
                 }
                 TagType::Svg => {
-                    quote_spanned! { node.name().span() => ::leptos::tachys::svg::#name() }
+                    quote_spanned! { node.name().span() => ::leptos_blitz::tachys::svg::#name() }
                 }
                 TagType::Math => {
-                    quote_spanned! { node.name().span() => ::leptos::tachys::math::#name() }
+                    quote_spanned! { node.name().span() => ::leptos_blitz::tachys::mathml::#name() }
                 }
             }
         } else {
@@ -1035,7 +1035,7 @@ pub(crate) fn attribute_absolute(
                             let key_name = key.to_string();
                             if key_name == "class" || key_name == "style" {
                                 Some(
-                                    quote! { ::leptos::tachys::html::#key::#key(#value) },
+                                    quote! { ::leptos_blitz::tachys::html::#key::#key(#value) },
                                 )
                             } else if key_name == "aria" {
                                 let value = attribute_value(node, true);
@@ -1044,7 +1044,7 @@ pub(crate) fn attribute_absolute(
                                 let fn_name = parts_iter.map(|p| p.to_string()).collect::<Vec<String>>().join("_");
                                 let key = Ident::new(&fn_name, key.span());
                                 Some(
-                                    quote! { ::leptos::tachys::html::attribute::#key(#value) },
+                                    quote! { ::leptos_blitz::tachys::html::attribute::#key(#value) },
                                 )
                             } else if multipart {
                                 // e.g., attr:data-foo="bar"
@@ -1053,11 +1053,11 @@ pub(crate) fn attribute_absolute(
                                     End(n) => n.to_string(),
                                 }).collect::<String>();
                                 Some(
-                                    quote! { ::leptos::tachys::html::attribute::custom::custom_attribute(#key_name, #value) },
+                                    quote! { ::leptos_blitz::tachys::html::attribute::custom::custom_attribute(#key_name, #value) },
                                 )
                             } else {
                                 Some(
-                                    quote! { ::leptos::tachys::html::attribute::#key(#value) },
+                                    quote! { ::leptos_blitz::tachys::html::attribute::#key(#value) },
                                 )
                             }
                         } else if id == "use" {
@@ -1069,7 +1069,7 @@ pub(crate) fn attribute_absolute(
                             };
                             Some(
                                 quote! {
-                                    ::leptos::tachys::html::directive::directive(
+                                    ::leptos_blitz::tachys::html::directive::directive(
                                         #key,
                                         #[allow(clippy::useless_conversion)] #param
                                     )
@@ -1082,14 +1082,14 @@ pub(crate) fn attribute_absolute(
                                 .replacen("style:", "", 1)
                                 .replacen("class:", "", 1);
                             Some(
-                                quote! { ::leptos::tachys::html::#id::#id((#key, #value)) },
+                                quote! { ::leptos_blitz::tachys::html::#id::#id((#key, #value)) },
                             )
                         } else if id == "prop" {
                             let value = attribute_value(node, false);
                             let key = &node.key.to_string();
                             let key = key.replacen("prop:", "", 1);
                             Some(
-                                quote! { ::leptos::tachys::html::property::#id(#key, #value) },
+                                quote! { ::leptos_blitz::tachys::html::property::#id(#key, #value) },
                             )
                         } else if id == "on" {
                             let key = &node.key.to_string();
@@ -1097,7 +1097,7 @@ pub(crate) fn attribute_absolute(
                             let (on, ty, handler) =
                                 event_type_and_handler(&key, node);
                             Some(
-                                quote! { ::leptos::tachys::html::event::#on(#ty, #handler) },
+                                quote! { ::leptos_blitz::tachys::html::event::#on(#ty, #handler) },
                             )
                         } else {
                             proc_macro_error2::abort!(
@@ -1126,12 +1126,12 @@ pub(crate) fn attribute_absolute(
             }
             else if name.contains('-') && !name.starts_with("aria-") {
                 quote! {
-                    ::leptos::tachys::html::attribute::custom::custom_attribute(#name, #value)
+                    ::leptos_blitz::tachys::html::attribute::custom::custom_attribute(#name, #value)
                 }
             }
             else {
                 quote! {
-                    ::leptos::tachys::html::attribute::#key(#value)
+                    ::leptos_blitz::tachys::html::attribute::#key(#value)
                 }
             }
         }),
@@ -1169,8 +1169,7 @@ pub(crate) fn event_type_and_handler(
 ) -> (TokenStream, TokenStream, TokenStream) {
     let handler = attribute_value(node, false);
 
-    let (event_type, is_custom, is_force_undelegated, is_targeted) =
-        parse_event_name(name);
+    let (event_type, is_custom, options) = parse_event_name(name);
 
     let event_name_ident = match &node.key {
         NodeName::Punctuated(parts) => {
@@ -1182,9 +1181,9 @@ pub(crate) fn event_type_and_handler(
         }
         _ => unreachable!(),
     };
-    let undelegated_ident = match &node.key {
+    let capture_ident = match &node.key {
         NodeName::Punctuated(parts) => {
-            parts.iter().find(|part| part.to_string() == "undelegated")
+            parts.iter().find(|part| part.to_string() == "capture")
         }
         _ => unreachable!(),
     };
@@ -1192,11 +1191,14 @@ pub(crate) fn event_type_and_handler(
         NodeName::Punctuated(parts) => &parts[0],
         _ => unreachable!(),
     };
-    let on = if is_targeted {
-        Ident::new("on_target", on.span()).to_token_stream()
-    } else {
-        on.to_token_stream()
-    };
+    if options.targeted {
+        abort!(
+            node.key.span(),
+            "`:target` is not supported, as event targets aren't typed by \
+             element; use the event's `target()` instead"
+        );
+    }
+    let on = on.to_token_stream();
     let event_type = if is_custom {
         event_type
     } else if let Some(ev_name) = event_name_ident {
@@ -1205,15 +1207,18 @@ pub(crate) fn event_type_and_handler(
         event_type
     };
 
-    let event_type = if is_force_undelegated {
-        let undelegated = if let Some(undelegated) = undelegated_ident {
-            quote! { #undelegated }
+    let event_type = quote! {
+        ::leptos_blitz::tachys::html::event::#event_type
+    };
+    let event_type = if options.captured {
+        let capture = if let Some(capture) = capture_ident {
+            quote! { #capture }
         } else {
-            quote! { undelegated }
+            quote! { capture }
         };
-        quote! { ::leptos::tachys::html::event::#undelegated(::leptos::tachys::html::event::#event_type) }
+        quote! { ::leptos_blitz::tachys::html::event::#capture(#event_type) }
     } else {
-        quote! { ::leptos_blitz::tachys::html::event::#event_type }
+        event_type
     };
 
     (on, event_type, handler)
@@ -1419,13 +1424,22 @@ fn is_ambiguous_element(tag: &str) -> bool {
     tag == "a" || tag == "script" || tag == "title"
 }
 
-fn parse_event(event_name: &str) -> (String, bool, bool) {
-    let is_undelegated = event_name.contains(":undelegated");
-    let is_targeted = event_name.contains(":target");
+fn parse_event(event_name: &str) -> (String, EventNameOptions) {
+    // every listener is added to its own element rather than delegated, so
+    // `:undelegated` changes nothing
+    let targeted = event_name.contains(":target");
+    let captured = event_name.contains(":capture");
     let event_name = event_name
         .replace(":undelegated", "")
-        .replace(":target", "");
-    (event_name, is_undelegated, is_targeted)
+        .replace(":target", "")
+        .replace(":capture", "");
+    (
+        event_name,
+        EventNameOptions {
+            targeted,
+            captured,
+        },
+    )
 }
 
 /// Escapes Rust keywords that are also HTML attribute names
@@ -1465,7 +1479,7 @@ fn attribute_value(
                     }
                 } else {
                     quote! {
-                        ::leptos::prelude::IntoAttributeValue::into_attribute_value(#expr)
+                        ::leptos_blitz::prelude::IntoAttributeValue::into_attribute_value(#expr)
                     }
                 }
             }
@@ -1473,7 +1487,7 @@ fn attribute_value(
             KVAttributeValue::InvalidBraced(block) => {
                 if is_attribute_proper {
                     quote! {
-                        ::leptos::prelude::IntoAttributeValue::into_attribute_value(#block)
+                        ::leptos_blitz::prelude::IntoAttributeValue::into_attribute_value(#block)
                     }
                 } else {
                     quote! {
@@ -1617,8 +1631,16 @@ const TYPED_EVENTS: [&str; 126] = [
 
 const CUSTOM_EVENT: &str = "Custom";
 
-pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool, bool) {
-    let (name, is_force_undelegated, is_targeted) = parse_event(name);
+#[derive(Debug)]
+pub(crate) struct EventNameOptions {
+    targeted: bool,
+    captured: bool,
+}
+
+pub(crate) fn parse_event_name(
+    name: &str,
+) -> (TokenStream, bool, EventNameOptions) {
+    let (name, options) = parse_event(name);
 
     let (event_type, is_custom) = TYPED_EVENTS
         .binary_search(&name.as_str())
@@ -1634,7 +1656,7 @@ pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool, bool) {
     } else {
         event_type
     };
-    (event_type, is_custom, is_force_undelegated, is_targeted)
+    (event_type, is_custom, options)
 }
 
 fn convert_to_snake_case(name: String) -> String {
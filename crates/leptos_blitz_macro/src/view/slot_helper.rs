@@ -74,24 +74,17 @@ pub(crate) fn slot_to_tokens(
 
     let items_to_clone = filter_prefixed_attrs(attrs.iter(), "clone:");
 
-    let dyn_attrs = attrs
+    // slots are plain props structs rather than elements, so there is nothing
+    // that `attr:` could be applied to
+    if let Some(attr) = attrs
         .iter()
-        .filter(|attr| attr.key.to_string().starts_with("attr:"))
-        .filter_map(|attr| {
-            let name = &attr.key.to_string();
-            let name = name.strip_prefix("attr:");
-            let value = attr.value().map(|v| {
-                quote! { #v }
-            })?;
-            Some(quote! { (#name, ::leptos::IntoAttribute::into_attribute(#value)) })
-        })
-        .collect::<Vec<_>>();
-
-    let dyn_attrs = if dyn_attrs.is_empty() {
-        quote! {}
-    } else {
-        quote! { .dyn_attrs(vec![#(#dyn_attrs),*]) }
-    };
+        .find(|attr| attr.key.to_string().starts_with("attr:"))
+    {
+        proc_macro_error2::abort!(
+            attr.key.span(),
+            "`attr:` syntax is not supported on slots"
+        );
+    }
 
     let mut slots = HashMap::new();
     let children = if node.children.is_empty() {
@@ -144,7 +137,7 @@ pub(crate) fn slot_to_tokens(
                     .children({
                         #(#clonables)*
 
-                        ::leptos::children::ToChildren::to_children(move || #children #view_marker)
+                        ::leptos_blitz::children::ToChildren::to_children(move || #children #view_marker)
                     })
                 }
             }
@@ -182,8 +175,7 @@ pub(crate) fn slot_to_tokens(
                 #(#props)*
                 #(#slots)*
                 #children
-                #build
-                #dyn_attrs;
+                #build;
 
             #[allow(unreachable_code, clippy::useless_conversion)]
             slot.into()
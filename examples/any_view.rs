@@ -0,0 +1,33 @@
+use leptos_blitz::prelude::*;
+
+#[component]
+fn Badge(level: ReadSignal<u8>) -> impl IntoView {
+    // each arm is a different view type, so they are erased into one
+    move || match level.get() {
+        0 => view! { <span>"new"</span> }.into_any(),
+        1 => view! { <strong>"hot"</strong> }.into_any(),
+        _ => view! { <em>"legendary"</em> }.into_any(),
+    }
+}
+
+#[component]
+fn Score(level: ReadSignal<u8>) -> impl IntoView {
+    // the memo itself is the view, and takes the attributes on every rebuild
+    Memo::new(move |_| view! { <span>{format!(" level {}", level.get())}</span> })
+}
+
+fn main() {
+    launch(move || {
+        let (level, set_level) = signal(0u8);
+        let next = move |_| set_level.update(|level| *level = (*level + 1) % 3);
+        let shared = view! { <{..} style="padding: 4px" title="Spread attributes" /> };
+
+        view! {
+            <main>
+                <Badge level {..} style="color: crimson" on:click=next />
+                <Score level {..} class="score" style="font-weight: bold" />
+                <div {..shared}>"Click the badge to level it up."</div>
+            </main>
+        }
+    })
+}
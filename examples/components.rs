@@ -0,0 +1,55 @@
+use leptos_blitz::prelude::*;
+
+#[slot]
+struct Footer {
+    children: ChildrenFn,
+}
+
+#[component]
+fn Card(#[prop(into)] label: String, children: Children, footer: Footer) -> impl IntoView {
+    view! {
+        <section style="border: 1px solid #ccc; margin: 8px; padding: 8px">
+            <h2>{label}</h2>
+            {children()}
+            <footer style="color: #666">{(footer.children)()}</footer>
+        </section>
+    }
+}
+
+#[component]
+fn PlusIcon(#[prop(into)] color: String) -> impl IntoView {
+    view! {
+        <svg viewBox="0 0 24 24" width="16" height="16">
+            <path d="M12 5v14M5 12h14" stroke=color stroke-width="2" stroke-linecap="round" />
+        </svg>
+    }
+}
+
+fn main() {
+    launch(move || {
+        let count = RwSignal::new(0);
+
+        view! {
+            <main>
+                <Card label="Counter" attr:data-kind="counter">
+                    <button on:click=move |_| count.update(|n| *n += 1)>
+                        <PlusIcon color="green" />
+                        "Increment"
+                    </button>
+                    <p class="value" class:even=move || count.get() % 2 == 0>
+                        "Value: " {move || count.get()}
+                    </p>
+                    <div
+                        style="height: 8px; background: green"
+                        style:width=move || format!("{}px", count.get() * 10)
+                    ></div>
+                    {move || (count.get() % 2 == 0).then(|| view! { <p>"Even"</p> })}
+                    <ul>
+                        {move || (0..count.get()).map(|n| view! { <li>{n}</li> }).collect::<Vec<_>>()}
+                    </ul>
+                    <Footer slot>"Clicked " {move || count.get()} " times"</Footer>
+                </Card>
+            </main>
+        }
+    })
+}
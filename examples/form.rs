@@ -0,0 +1,55 @@
+use leptos_blitz::{html, prelude::*, tachys::renderer::types::Element};
+
+/// Outlines an element in the given color.
+fn outline(el: Element, color: &'static str) {
+    el.style()
+        .set_property("outline", &format!("2px solid {color}"));
+}
+
+fn main() {
+    launch(move || {
+        let name = RwSignal::new(String::from("Blitz"));
+        let subscribed = RwSignal::new(true);
+        let plan = RwSignal::new(1);
+        let name_input = NodeRef::<html::Input>::new();
+
+        view! {
+            <main>
+                <input
+                    type="text"
+                    node_ref=name_input
+                    prop:value=move || name.get()
+                    on:input=move |ev| {
+                        if let Some(target) = ev.target() {
+                            name.set(target.value());
+                        }
+                    }
+                />
+                <button on:click=move |_| {
+                    name.set(String::new());
+                    if let Some(input) = name_input.get() {
+                        input.focus();
+                    }
+                }>"Clear"</button>
+                <label>
+                    <input
+                        type="checkbox"
+                        prop:checked=move || subscribed.get()
+                        on:click=move |_| subscribed.update(|s| *s = !*s)
+                    />
+                    "Subscribe"
+                </label>
+                <select use:outline="orange" prop:selectedIndex=move || plan.get() prop:disabled=move || !subscribed.get()>
+                    <option>"Free"</option>
+                    <option>"Pro"</option>
+                    <option>"Team"</option>
+                </select>
+                <button on:click=move |_| plan.update(|p| *p = (*p + 1) % 3)>"Next plan"</button>
+                <p>
+                    "Hello, " {move || name.get()} "! "
+                    {move || if subscribed.get() { "Subscribed." } else { "Not subscribed." }}
+                </p>
+            </main>
+        }
+    })
+}
@@ -0,0 +1,23 @@
+use leptos_blitz::prelude::*;
+
+fn main() {
+    launch(move || {
+        let source = RwSignal::new(String::from("<h2>Preview</h2><p>Some <em>rich</em> text.</p>"));
+
+        view! {
+            <main>
+                <input
+                    type="text"
+                    style="width: 100%"
+                    prop:value=move || source.get()
+                    on:input=move |ev| {
+                        if let Some(target) = ev.target() {
+                            source.set(target.value());
+                        }
+                    }
+                />
+                <div style="border: 1px solid #ccc; padding: 8px" inner_html=move || source.get()></div>
+            </main>
+        }
+    })
+}
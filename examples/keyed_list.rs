@@ -0,0 +1,62 @@
+use leptos_blitz::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Row {
+    id: usize,
+    count: RwSignal<i32>,
+}
+
+fn main() {
+    launch(move || {
+        let next_id = RwSignal::new(3);
+        let rows = RwSignal::new(
+            (0..3)
+                .map(|id| Row {
+                    id,
+                    count: RwSignal::new(0),
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let add = move |_| {
+            let id = next_id.get_untracked();
+            next_id.set(id + 1);
+            rows.update(|rows| {
+                rows.push(Row {
+                    id,
+                    count: RwSignal::new(0),
+                })
+            });
+        };
+        let reverse = move |_| rows.update(|rows| rows.reverse());
+        let remove_first = move |_| {
+            rows.update(|rows| {
+                if !rows.is_empty() {
+                    rows.remove(0);
+                }
+            })
+        };
+
+        view! {
+            <main>
+                <button on:click=add>"Add row"</button>
+                <button on:click=reverse>"Reverse"</button>
+                <button on:click=remove_first>"Remove first"</button>
+                <ul>
+                    <ForEnumerate
+                        each=move || rows.get()
+                        key=|row| row.id
+                        let(index, row)
+                    >
+                        <li>
+                            {move || index.get()} ". Row " {row.id} ": "
+                            <button on:click=move |_| row.count.update(|n| *n += 1)>
+                                {move || row.count.get()}
+                            </button>
+                        </li>
+                    </ForEnumerate>
+                </ul>
+            </main>
+        }
+    })
+}
@@ -0,0 +1,38 @@
+use leptos_blitz::prelude::*;
+use std::time::Duration;
+
+async fn load_fact(n: u32) -> String {
+    tokio::time::sleep(Duration::from_millis(800)).await;
+    format!("{n} squared is {}", n * n)
+}
+
+fn main() {
+    launch(move || {
+        let (n, set_n) = signal(1u32);
+        let (pending, set_pending) = signal(false);
+        let fact = AsyncDerived::new(move || load_fact(n.get()));
+        let next = move |_| set_n.update(|n| *n += 1);
+
+        view! {
+            <main>
+                <button on:click=next>"Next number"</button>
+                <h2>"Suspense"</h2>
+                // falls back every time the fact is reloading
+                <Suspense fallback=|| view! { <p>"Loading..."</p> }>
+                    <p>{move || Suspend::new(async move { fact.await })}</p>
+                </Suspense>
+                <h2>"Transition"</h2>
+                // keeps the previous fact on screen while the next one loads
+                <Transition fallback=|| view! { <p>"Loading..."</p> } set_pending>
+                    <p style:opacity=move || if pending.get() { "0.5" } else { "1" }>
+                        {move || fact.get()}
+                    </p>
+                </Transition>
+                <h2>"Await"</h2>
+                <Await future=load_fact(12) let:fact>
+                    <p>{fact.clone()}</p>
+                </Await>
+            </main>
+        }
+    })
+}